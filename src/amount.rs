@@ -0,0 +1,336 @@
+use crate::Currency;
+use num_bigfloat::BigFloat;
+use std::str::FromStr;
+
+const GROUPING: [char; 6] = [' ', '\u{a0}', '\u{202f}', '\u{2009}', '\'', '’'];
+
+/// Splits a locale such as `en_US` or `es-MX` into its language and region
+fn split_locale(locale: &str) -> (String, String) {
+    let mut parts = locale.trim().split(['_', '-']);
+    let lang = parts.next().unwrap_or_default().to_lowercase();
+    let region = parts.next().unwrap_or_default().to_uppercase();
+    (lang, region)
+}
+
+/// Whether the locale writes `1.250,50` rather than `1,250.50`
+fn decimal_comma(lang: &str, region: &str) -> bool {
+    match (lang, region) {
+        (_, "CH" | "LI") => false,
+        ("es", "DO" | "GT" | "HN" | "MX" | "NI" | "PA" | "PE" | "PR" | "SV" | "US") => false,
+        ("en", "ZA") => true,
+        ("" | "en" | "ga" | "he" | "hi" | "ja" | "ko" | "ms" | "th" | "zh", _) => false,
+        _ => true,
+    }
+}
+
+/// Currency written with a `$` sign in the given region
+fn dollar(region: &str) -> Currency {
+    match region {
+        "AR" => Currency::ARS,
+        "AU" => Currency::AUD,
+        "BB" => Currency::BBD,
+        "BM" => Currency::BMD,
+        "BN" => Currency::BND,
+        "BS" => Currency::BSD,
+        "BZ" => Currency::BZD,
+        "CA" => Currency::CAD,
+        "CL" => Currency::CLP,
+        "CO" => Currency::COP,
+        "CU" => Currency::CUP,
+        "DO" => Currency::DOP,
+        "FJ" => Currency::FJD,
+        "GY" => Currency::GYD,
+        "HK" => Currency::HKD,
+        "JM" => Currency::JMD,
+        "KY" => Currency::KYD,
+        "LR" => Currency::LRD,
+        "MX" => Currency::MXN,
+        "NA" => Currency::NAD,
+        "NZ" => Currency::NZD,
+        "SB" => Currency::SBD,
+        "SG" => Currency::SGD,
+        "SR" => Currency::SRD,
+        "TT" => Currency::TTD,
+        "TW" => Currency::TWD,
+        "UY" => Currency::UYU,
+        "AS" | "BQ" | "EC" | "FM" | "GU" | "MH" | "MP" | "PA" | "PR" | "PW" | "SV" | "TC"
+        | "TL" | "US" | "VG" | "VI" => Currency::USD,
+        _ => Currency::DOLLAR,
+    }
+}
+
+/// Finds the currency written as `symbol`, using the region for the symbols
+/// shared by several currencies
+fn from_symbol(symbol: &str, region: &str) -> Option<Currency> {
+    Some(match symbol {
+        "$" => dollar(region),
+        "£" => match region {
+            "EG" => Currency::EGP,
+            "FK" => Currency::FKP,
+            "GI" => Currency::GIP,
+            "LB" => Currency::LBP,
+            "SD" => Currency::SDG,
+            "SH" => Currency::SHP,
+            "SS" => Currency::SSP,
+            "SY" => Currency::SYP,
+            _ => Currency::GBP,
+        },
+        "¥" => match region {
+            "CN" => Currency::CNY,
+            _ => Currency::JPY,
+        },
+        "kr" | "kr." => match region {
+            "DK" | "FO" | "GL" => Currency::DKK,
+            "IS" => Currency::ISK,
+            "NO" | "SJ" => Currency::NOK,
+            "SE" => Currency::SEK,
+            _ => return None,
+        },
+        "₨" | "Rs" | "Rs." => match region {
+            "LK" => Currency::LKR,
+            "MU" => Currency::MUR,
+            "NP" => Currency::NPR,
+            "PK" => Currency::PKR,
+            "SC" => Currency::SCR,
+            _ => return None,
+        },
+        "C$" => match region {
+            "NI" => Currency::NIO,
+            _ => Currency::CAD,
+        },
+        "Bs" | "Bs." => match region {
+            "VE" => Currency::VES,
+            _ => Currency::BOB,
+        },
+        "€" => Currency::EUR,
+        "US$" | "U$S" => Currency::USD,
+        "CA$" => Currency::CAD,
+        "A$" | "AU$" => Currency::AUD,
+        "NZ$" => Currency::NZD,
+        "HK$" => Currency::HKD,
+        "S$" => Currency::SGD,
+        "NT$" => Currency::TWD,
+        "MX$" => Currency::MXN,
+        "RD$" => Currency::DOP,
+        "J$" => Currency::JMD,
+        "TT$" => Currency::TTD,
+        "R$" => Currency::BRL,
+        "B/." => Currency::PAB,
+        "S/" | "S/." => Currency::PEN,
+        "Q" => Currency::GTQ,
+        "R" => Currency::ZAR,
+        "Fr." | "fr." => Currency::CHF,
+        "Ft" => Currency::HUF,
+        "Kč" => Currency::CZK,
+        "zł" => Currency::PLN,
+        "Rp" => Currency::IDR,
+        "RM" => Currency::MYR,
+        "грн" | "грн." => Currency::UAH,
+        "円" => Currency::JPY,
+        "元" => Currency::CNY,
+        "₴" => Currency::UAH,
+        "₹" => Currency::INR,
+        "₩" => match region {
+            "KP" => Currency::KPW,
+            _ => Currency::KRW,
+        },
+        "₪" => Currency::ILS,
+        "₽" => Currency::RUB,
+        "₺" => Currency::TRY,
+        "₫" => Currency::VND,
+        "฿" => Currency::THB,
+        "₱" => Currency::PHP,
+        "₦" => Currency::NGN,
+        "₸" => Currency::KZT,
+        "₲" => Currency::PYG,
+        "₡" => Currency::CRC,
+        "₭" => Currency::LAK,
+        "₮" => Currency::MNT,
+        "₼" => Currency::AZN,
+        "₾" => Currency::GEL,
+        "₵" => Currency::GHS,
+        "₿" => Currency::BTC,
+        "Ξ" => Currency::ETH,
+        // ISO 4217 codes, e.g. `USD 5`
+        code if code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic()) => {
+            Currency::from_str(&code.to_uppercase()).ok()?
+        }
+        _ => return None,
+    })
+}
+
+/// Normalizes the digits and separators of an amount, e.g. `1.250,50`, to
+/// `1250.50`
+fn normalize(num: &str, decimal_comma: bool) -> Option<String> {
+    let num: String = num.chars().filter(|c| !GROUPING.contains(c)).collect();
+    if !num
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '.' || c == ',')
+        || num.contains(",,")
+        || num.contains("..")
+    {
+        return None;
+    }
+
+    let decimal = match (num.rfind('.'), num.rfind(',')) {
+        (None, None) => None,
+        // with both separators, the decimal one comes last
+        (Some(dot), Some(comma)) => Some(if dot > comma { '.' } else { ',' }),
+        (Some(_), None) => ambiguous(&num, '.', !decimal_comma),
+        (None, Some(_)) => ambiguous(&num, ',', decimal_comma),
+    };
+
+    let (whole, fraction) = match decimal {
+        Some(decimal) => num.rsplit_once(decimal)?,
+        None => (num.as_str(), ""),
+    };
+    // groups of three digits, or of two before the last one, e.g. `1,25,000`
+    let groups: Vec<&str> = whole.split(['.', ',']).collect();
+    if fraction.contains(['.', ','])
+        || (groups.len() > 1
+            && (!(1..=3).contains(&groups[0].len())
+                || groups[groups.len() - 1].len() != 3
+                || groups[1..].iter().any(|g| !(2..=3).contains(&g.len()))))
+    {
+        return None;
+    }
+    let whole: String = whole.chars().filter(char::is_ascii_digit).collect();
+    match fraction {
+        "" => Some(whole),
+        _ => Some(format!("{}.{}", whole, fraction)),
+    }
+}
+
+/// Whether a lone kind of separator is decimal, e.g. `1,25` or `42.01`,
+/// or grouping, e.g. `1,250,000`; `1,250` follows the locale
+fn ambiguous(num: &str, separator: char, locale_decimal: bool) -> Option<char> {
+    let (_, fraction) = num.rsplit_once(separator).unwrap_or_default();
+    if num.matches(separator).count() > 1 || (fraction.len() == 3 && !locale_decimal) {
+        None
+    } else {
+        Some(separator)
+    }
+}
+
+/// Parses an amount of money, e.g. `$42.01` or `42,01 €`, into its value and
+/// currency
+pub(crate) fn parse_amount(amount: &str, locale: &str) -> Option<(BigFloat, Currency)> {
+    let (lang, region) = split_locale(locale);
+    let amount = amount.trim();
+    let start = amount.find(|c: char| c.is_ascii_digit())?;
+    let end = amount.rfind(|c: char| c.is_ascii_digit())? + 1;
+    let (prefix, suffix) = (&amount[..start], &amount[end..]);
+
+    // the sign goes either before or after a leading symbol, e.g. `-$5` or `$-5`
+    let negative = prefix.contains(['-', '−']);
+    let prefix = prefix.replace(['-', '−', '+'], "");
+    let symbol = match (prefix.trim(), suffix.trim()) {
+        (symbol, "") | ("", symbol) if !symbol.is_empty() => symbol,
+        _ => return None,
+    };
+    let currency = from_symbol(symbol, &region)?;
+
+    let num = normalize(&amount[start..end], decimal_comma(&lang, &region))?;
+    let num = BigFloat::parse(&num)?;
+    Some((if negative { -num } else { num }, currency))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(amount: &str, locale: &str) -> Option<(String, Currency)> {
+        parse_amount(amount, locale).map(|(num, currency)| (num.to_string(), currency))
+    }
+
+    fn value(num: &str) -> String {
+        BigFloat::parse(num).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(
+            parse("$42.01", ""),
+            Some((value("42.01"), Currency::DOLLAR))
+        );
+        assert_eq!(
+            parse("42,01 €", "fr"),
+            Some((value("42.01"), Currency::EUR))
+        );
+        assert_eq!(parse("42,01 €", ""), Some((value("42.01"), Currency::EUR)));
+        assert_eq!(
+            parse("£1,250", "en_GB"),
+            Some((value("1250"), Currency::GBP))
+        );
+        assert_eq!(
+            parse("R$ 10,50", "pt_BR"),
+            Some((value("10.5"), Currency::BRL))
+        );
+        assert_eq!(parse("₴100", "uk"), Some((value("100"), Currency::UAH)));
+        assert_eq!(parse("USD 5", ""), Some((value("5"), Currency::USD)));
+        assert_eq!(parse("5 usd", ""), Some((value("5"), Currency::USD)));
+        assert_eq!(parse("-$5", ""), Some((value("-5"), Currency::DOLLAR)));
+        assert_eq!(parse("$-5", ""), Some((value("-5"), Currency::DOLLAR)));
+    }
+
+    #[test]
+    fn test_separators() {
+        assert_eq!(parse("1.250 €", "es"), Some((value("1250"), Currency::EUR)));
+        assert_eq!(
+            parse("1.250 €", "en_IE"),
+            Some((value("1.25"), Currency::EUR))
+        );
+        assert_eq!(
+            parse("1 250,50 €", "fr"),
+            Some((value("1250.5"), Currency::EUR))
+        );
+        assert_eq!(
+            parse("1\u{202f}250,50 €", "fr"),
+            Some((value("1250.5"), Currency::EUR))
+        );
+        assert_eq!(
+            parse("CHF 1'250.50", "fr_CH"),
+            Some((value("1250.5"), Currency::CHF))
+        );
+        assert_eq!(
+            parse("1.234.567,89 €", ""),
+            Some((value("1234567.89"), Currency::EUR))
+        );
+        assert_eq!(
+            parse("$1,234,567", "es_MX"),
+            Some((value("1234567"), Currency::MXN))
+        );
+        assert_eq!(
+            parse("$1,250", "es_AR"),
+            Some((value("1.25"), Currency::ARS))
+        );
+        assert_eq!(
+            parse("₹1,25,000", "en_IN"),
+            Some((value("125000"), Currency::INR))
+        );
+        assert_eq!(parse("$1,,250", ""), None);
+        assert_eq!(parse("$12,34,5", ""), None);
+        assert_eq!(parse("$1.2,5", ""), None);
+    }
+
+    #[test]
+    fn test_symbols() {
+        assert_eq!(parse("$5", "en_US").unwrap().1, Currency::USD);
+        assert_eq!(parse("$5", "en_CA").unwrap().1, Currency::CAD);
+        assert_eq!(parse("$5", "fr_CA").unwrap().1, Currency::CAD);
+        assert_eq!(parse("$5", "en_AU").unwrap().1, Currency::AUD);
+        assert_eq!(parse("C$5", "es_NI").unwrap().1, Currency::NIO);
+        assert_eq!(parse("C$5", "").unwrap().1, Currency::CAD);
+        assert_eq!(parse("¥500", "").unwrap().1, Currency::JPY);
+        assert_eq!(parse("¥500", "zh_CN").unwrap().1, Currency::CNY);
+        assert_eq!(parse("100 kr", "sv_SE").unwrap().1, Currency::SEK);
+        assert_eq!(parse("₿0.5", "").unwrap().1, Currency::BTC);
+        assert_eq!(parse("0.00012345 BTC", "").unwrap().1, Currency::BTC);
+        assert_eq!(parse("100 kr", ""), None);
+        assert_eq!(parse("5 XYZ", ""), None);
+        assert_eq!(parse("42", ""), None);
+        assert_eq!(parse("$", ""), None);
+        assert_eq!(parse("$5 €", ""), None);
+        assert_eq!(parse("5 km", ""), None);
+    }
+}
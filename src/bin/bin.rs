@@ -1,4 +1,4 @@
-use ::num2words::{Currency, Lang, Num2Words};
+use ::num2words::{Currency, Lang, Num2Words, Unit};
 use std::env;
 use std::str::FromStr;
 
@@ -7,6 +7,9 @@ const HELP: &str = r#"NAME:
 
 USAGE:
     num2words <number> [options]
+    num2words <hh:mm[:ss] [am|pm]> [options]
+    num2words <amount, e.g. $42.01 or 42,01 €> [options]
+    num2words <roman numeral> [options]
     num2words --help
 
 VERSION:
@@ -22,10 +25,21 @@ GLOBAL OPTIONS:
 
 AVAILABLE LANGUAGES:
     en:      English
-    fr:      French (France and Canada)
+    en_US:   English (United States)
+    en_GB:   English (United Kingdom)
+    en_IN:   English (India)
+    en_AU:   English (Australia)
+    en_CA:   English (Canada)
+    fr:      French (France)
     fr_BE:   French (Belgium and the Democratic Republic of the Congo)
     fr_CH:   French (Swiss Confederation and Aosta Valley)
+    fr_CA:   French (Canada)
     es:      Spanish
+    es_ES:   Spanish (Spain)
+    es_MX:   Spanish (Mexico)
+    es_AR:   Spanish (Argentina)
+    es_CO:   Spanish (Colombia)
+    es_CL:   Spanish (Chile)
     uk:      Ukrainian
 
 AVAILABLE OUTPUTS:
@@ -33,11 +47,30 @@ AVAILABLE OUTPUTS:
     ordinal:       forty-second (42)
     ordinal_num:   42nd (42)
     year:          nineteen oh-one (1901)
+    percent:       forty-two percent (42)
+    permille:      forty-two per mille (42)
+    scientific:    one times ten to the sixth (1e6)
+    engineering:   twelve times ten to the third (12e3)
+    compact:       two point five million (2500000)
+    digits:        five five five zero one four two (555-0142)
+    roman:         XLII (42)
+    regnal:        Henry the eighth (Henry VIII)
     currency:      forty-two dollars and one cent (42.01)
+    cheque:USD:    forty-two and 01/100 dollars (42.01)
+    compact:USD:   two point five million US dollars (2500000)
 
 AVAILABLE CURRENCIES:
-    ISO 4217 code      - USD, EUR, GBP, etc.
-    generic currencies - DINAR, DOLLAR, PESO, RIYAL"#;
+    ISO 4217 code      - USD, EUR, GBP, 978, etc.
+    generic currencies - DINAR, DOLLAR, PESO, RIYAL
+    symbols            - B, C, Q
+    cryptocurrencies   - BTC, ETH
+
+AVAILABLE UNITS:
+    length      - km, m, cm, mm, mi, ft, in
+    mass        - t, kg, g, lb
+    volume      - l, ml
+    temperature - °C, °F
+    speed       - km/h, mph"#;
 
 fn get_version() -> String {
     let version = env!("CARGO_PKG_VERSION");
@@ -58,8 +91,26 @@ fn help() {
     println!("{}", HELP.replace("{{VERSION}}", get_version().as_str()))
 }
 
-fn handle_cmd(n: String, mut args: std::env::Args) {
-    if let Some(mut num) = Num2Words::parse(&n) {
+fn handle_cmd(n: String, args: std::env::Args) {
+    let args: Vec<String> = args.collect();
+    // digit sequences and regnal numbers are not plain numbers, so they are
+    // parsed as such from the start
+    let to = args
+        .windows(2)
+        .rev()
+        .find(|w| ["--to", "-t"].contains(&w[0].as_str()))
+        .map(|w| w[1].as_str());
+    let parsed = match to {
+        Some("digits") => Num2Words::parse_digits(&n),
+        Some("regnal") => Num2Words::parse_regnal(&n),
+        _ => Num2Words::parse_time(&n)
+            .or_else(|| Num2Words::parse_amount(&n))
+            .or_else(|| Num2Words::parse_measurement(&n))
+            .or_else(|| Num2Words::parse(&n)),
+    };
+
+    let mut args = args.into_iter();
+    if let Some(mut num) = parsed {
         while let Some(arg) = args.next() {
             match arg.as_str() {
                 "--lang" | "-l" => match args.next() {
@@ -87,6 +138,18 @@ fn handle_cmd(n: String, mut args: std::env::Args) {
                     Some(t) => {
                         if let Ok(v) = Currency::from_str(t.as_str()) {
                             num = num.currency(v);
+                        } else if let Some(v) = t
+                            .strip_prefix("cheque:")
+                            .and_then(|c| Currency::from_str(c).ok())
+                        {
+                            num = num.cheque(v);
+                        } else if let Some(v) = t
+                            .strip_prefix("compact:")
+                            .and_then(|c| Currency::from_str(c).ok())
+                        {
+                            num = num.compact_currency(v, 3);
+                        } else if let Ok(v) = Unit::from_str(t.as_str()) {
+                            num = num.unit(v);
                         } else {
                             match t.as_str() {
                                 "cardinal" => {
@@ -101,6 +164,25 @@ fn handle_cmd(n: String, mut args: std::env::Args) {
                                 "year" => {
                                     num = num.year();
                                 }
+                                "percent" => {
+                                    num = num.percent();
+                                }
+                                "permille" => {
+                                    num = num.permille();
+                                }
+                                "scientific" => {
+                                    num = num.scientific();
+                                }
+                                "engineering" => {
+                                    num = num.engineering();
+                                }
+                                "compact" => {
+                                    num = num.compact(3);
+                                }
+                                "digits" | "regnal" => {}
+                                "roman" => {
+                                    num = num.roman();
+                                }
                                 _ => {
                                     eprintln!("Error: invalid to tag");
                                     return;
@@ -1,111 +1,627 @@
+use crate::{Lang, Noun};
 use std::str::FromStr;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard};
 
-/// Defines currencies
-///
-/// Every three-letter variant is a valid ISO 4217 currency code. The only
-/// exceptions are `DINAR`, `DOLLAR`, `PESO` and `RIYAL`, which are generic
-/// terminology for the respective currencies.
-#[derive(Clone, Copy, Debug)]
-#[non_exhaustive]
-pub enum Currency {
-    /// Dirham
-    AED,
+// Generates the currencies from their table: the numeric code and minor-unit
+// exponent of ISO 4217, then the default (English) names of the unit and its
+// subdivision, `None` for the local word for a cent. Names are written either
+// with `{}` for the plural "s" (`dollar{}`) or as `singular|plural`.
+macro_rules! currencies {
+    ($(
+        $(#[$meta:meta])*
+        $variant:ident = $numeric:expr, $minor_units:expr, $name:expr, $subunit:expr;
+    )*) => {
+        /// Defines currencies
+        ///
+        /// Every three-letter variant is a valid ISO 4217 currency code. The
+        /// only exceptions are `DINAR`, `DOLLAR`, `PESO` and `RIYAL`, which are
+        /// generic terminology for the respective currencies, and `B`, `C` and
+        /// `Q`, the symbols of the balboa, the córdoba and the quetzal, and
+        /// the cryptocurrencies `BTC` and `ETH`.
+        ///
+        /// Other currencies can be defined at runtime, see [`CustomCurrency`].
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum Currency {
+            $($(#[$meta])* $variant,)*
+            /// Currency registered with [`CustomCurrency::register`]
+            Custom(&'static RegisteredCurrency),
+        }
+
+        impl Currency {
+            /// Returns every built-in currency, in alphabetical order of their
+            /// code
+            pub fn all() -> &'static [Currency] {
+                &[$(Currency::$variant,)*]
+            }
+
+            /// Returns the code of the currency, i.e. the name of its variant
+            /// such as `EUR`
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $(Currency::$variant => stringify!($variant),)*
+                    Currency::Custom(custom) => &custom.code,
+                }
+            }
+
+            /// Returns the ISO 4217 numeric code of the currency, e.g. `978`
+            /// for the euro
+            ///
+            /// Generic and custom currencies like `DOLLAR` have none.
+            pub fn numeric_code(&self) -> Option<u16> {
+                match self {
+                    $(Currency::$variant => $numeric,)*
+                    Currency::Custom(_) => None,
+                }
+            }
+
+            /// Returns the ISO 4217 minor-unit exponent of the currency, i.e.
+            /// the number of decimals of its amounts
+            ///
+            /// Currencies whose minor unit is no longer in use, like the yen,
+            /// have none, as do the units that are not money, like gold.
+            pub fn minor_units(&self) -> usize {
+                match self {
+                    $(Currency::$variant => $minor_units,)*
+                    Currency::Custom(custom) => custom.read().minor_units,
+                }
+            }
+
+            fn name(&self) -> &'static str {
+                match self {
+                    $(Currency::$variant => $name,)*
+                    Currency::Custom(custom) => &custom.code,
+                }
+            }
+
+            fn subunit(&self) -> Option<&'static str> {
+                match self {
+                    $(Currency::$variant => $subunit,)*
+                    Currency::Custom(_) => None,
+                }
+            }
+        }
+    };
+}
+
+currencies! {
+    /// UAE dirham
+    AED = Some(784), 2, "dirham{}", Some("fils");
+    /// Afghani
+    AFN = Some(971), 2, "afghani{}", Some("pul{}");
+    /// Albanian lek
+    ALL = Some(8), 2, "lek|lekë", Some("qindarka|qindarkë");
+    /// Armenian dram
+    AMD = Some(51), 2, "dram{}", Some("luma{}");
+    /// Angolan kwanza
+    AOA = Some(973), 2, "kwanza{}", Some("centimo{}");
     /// Argentine peso
-    ARS,
+    ARS = Some(32), 2, "argentine peso{}", Some("centavo{}");
     /// Australian dollar
-    AUD,
+    AUD = Some(36), 2, "australian dollar{}", None;
+    /// Aruban florin
+    AWG = Some(533), 2, "aruban florin{}", None;
+    /// Azerbaijani manat
+    AZN = Some(944), 2, "azerbaijani manat{}", Some("qapik{}");
     /// Panamanian balboa
-    B,
+    B = Some(590), 2, "balboa{}", Some("centesimo{}");
+    /// Convertible mark of Bosnia and Herzegovina
+    BAM = Some(977), 2, "convertible mark{}", Some("fening{}");
+    /// Barbados dollar
+    BBD = Some(52), 2, "barbados dollar{}", None;
+    /// Bangladeshi taka
+    BDT = Some(50), 2, "taka", Some("poisha");
+    /// Bulgarian lev
+    BGN = Some(975), 2, "bulgarian lev|bulgarian leva", Some("stotinka|stotinki");
+    /// Bahraini dinar
+    BHD = Some(48), 3, "bahraini dinar{}", Some("fils");
+    /// Burundian franc
+    BIF = Some(108), 0, "burundian franc{}", Some("centime{}");
+    /// Bermudian dollar
+    BMD = Some(60), 2, "bermudian dollar{}", None;
+    /// Brunei dollar
+    BND = Some(96), 2, "brunei dollar{}", None;
     /// Boliviano
-    BOB,
+    BOB = Some(68), 2, "boliviano{}", Some("centavo{}");
+    /// Bolivian Mvdol
+    BOV = Some(984), 2, "mvdol{}", None;
     /// Brazilian real
-    BRL,
+    BRL = Some(986), 2, "real|reais", Some("centavo{}");
+    /// Bahamian dollar
+    BSD = Some(44), 2, "bahamian dollar{}", None;
+    /// Bitcoin
+    BTC = None, 8, "bitcoin|bitcoin", Some("satoshi{}");
+    /// Bhutanese ngultrum
+    BTN = Some(64), 2, "ngultrum", Some("chhertum{}");
+    /// Botswana pula
+    BWP = Some(72), 2, "pula", Some("thebe");
+    /// Belarusian ruble
+    BYN = Some(933), 2, "belarusian ruble{}", Some("kopek{}");
+    /// Belize dollar
+    BZD = Some(84), 2, "belize dollar{}", None;
     /// Nicaraguan córdoba
-    C,
+    C = Some(558), 2, "cordoba{}", Some("centavo{}");
     /// Canadian dollar
-    CAD,
+    CAD = Some(124), 2, "canadian dollar{}", None;
+    /// Congolese franc
+    CDF = Some(976), 2, "congolese franc{}", Some("centime{}");
+    /// WIR euro
+    CHE = Some(947), 2, "WIR euro{}", None;
     /// Swiss franc
-    CHF,
+    CHF = Some(756), 2, "franc{}", Some("centime{}");
+    /// WIR franc
+    CHW = Some(948), 2, "WIR franc{}", Some("centime{}");
+    /// Chilean unit of account
+    CLF = Some(990), 4, "unidad de fomento|unidades de fomento", None;
     /// Chilean peso
-    CLP,
+    CLP = Some(152), 0, "chilean peso{}", Some("centavo{}");
     /// Chinese yuan
-    CNY,
+    CNY = Some(156), 2, "yuan", Some("fen");
     /// Colombian peso
-    COP,
+    COP = Some(170), 2, "colombian peso{}", Some("centavo{}");
+    /// Colombian unit of real value
+    COU = Some(970), 2, "unidad de valor real|unidades de valor real", None;
     /// Costa Rican colón
-    CRC,
+    CRC = Some(188), 2, "colon|colones", Some("centimo{}");
+    /// Cuban peso
+    CUP = Some(192), 2, "cuban peso{}", Some("centavo{}");
+    /// Cape Verdean escudo
+    CVE = Some(132), 2, "cape verdean escudo{}", Some("centavo{}");
+    /// Czech koruna
+    CZK = Some(203), 2, "czech koruna{}", Some("haler{}");
     /// Dinar
-    DINAR,
+    DINAR = None, 2, "dinar{}", None;
+    /// Djiboutian franc
+    DJF = Some(262), 0, "djiboutian franc{}", Some("centime{}");
+    /// Danish krone
+    DKK = Some(208), 2, "danish krone|danish kroner", Some("ore");
     /// Dollar
-    DOLLAR,
+    DOLLAR = None, 2, "dollar{}", None;
+    /// Dominican peso
+    DOP = Some(214), 2, "dominican peso{}", Some("centavo{}");
     /// Algerian dinar
-    DZD,
+    DZD = Some(12), 2, "algerian dinar{}", Some("centime{}");
+    /// Egyptian pound
+    EGP = Some(818), 2, "egyptian pound{}", Some("piastre{}");
+    /// Eritrean nakfa
+    ERN = Some(232), 2, "nakfa{}", None;
+    /// Ethiopian birr
+    ETB = Some(230), 2, "ethiopian birr{}", Some("santim{}");
+    /// Ether
+    ETH = None, 18, "ether|ether", Some("wei|wei");
     /// Euro
-    EUR,
+    EUR = Some(978), 2, "euro{}", None;
+    /// Fiji dollar
+    FJD = Some(242), 2, "fiji dollar{}", None;
+    /// Falkland Islands pound
+    FKP = Some(238), 2, "falkland islands pound{}", Some("penny|pence");
     /// British pound
-    GBP,
+    GBP = Some(826), 2, "pound{}", Some("penny|pence");
+    /// Georgian lari
+    GEL = Some(981), 2, "lari", Some("tetri");
+    /// Ghana cedi
+    GHS = Some(936), 2, "ghana cedi{}", Some("pesewa{}");
+    /// Gibraltar pound
+    GIP = Some(292), 2, "gibraltar pound{}", Some("penny|pence");
+    /// Gambian dalasi
+    GMD = Some(270), 2, "dalasi{}", Some("butut{}");
+    /// Guinean franc
+    GNF = Some(324), 0, "guinean franc{}", Some("centime{}");
+    /// Guatemalan quetzal
+    GTQ = Some(320), 2, "quetzal{}", Some("centavo{}");
+    /// Guyanese dollar
+    GYD = Some(328), 2, "guyanese dollar{}", None;
     /// Hong Kong dollar
-    HKD,
+    HKD = Some(344), 2, "hong kong dollar{}", None;
+    /// Honduran lempira
+    HNL = Some(340), 2, "lempira{}", Some("centavo{}");
+    /// Haitian gourde
+    HTG = Some(332), 2, "gourde{}", Some("centime{}");
+    /// Hungarian forint
+    HUF = Some(348), 2, "forint{}", Some("filler{}");
     /// Indonesian rupiah
-    IDR,
+    IDR = Some(360), 2, "indonesian rupiah{}", Some("sen");
     /// Israeli new shekel
-    ILS,
+    ILS = Some(376), 2, "new shekel{}", Some("agora|agorot");
     /// Indian rupee
-    INR,
+    INR = Some(356), 2, "rupee{}", Some("paisa|paise");
+    /// Iraqi dinar
+    IQD = Some(368), 3, "iraqi dinar{}", Some("fils");
+    /// Iranian rial
+    IRR = Some(364), 2, "iranian rial{}", Some("dinar{}");
+    /// Icelandic króna
+    ISK = Some(352), 0, "icelandic krona|icelandic kronur", Some("eyrir|aurar");
+    /// Jamaican dollar
+    JMD = Some(388), 2, "jamaican dollar{}", None;
+    /// Jordanian dinar
+    JOD = Some(400), 3, "jordanian dinar{}", Some("fils");
     /// Japanese yen
-    JPY,
+    JPY = Some(392), 0, "yen", Some("sen");
+    /// Kenyan shilling
+    KES = Some(404), 2, "kenyan shilling{}", None;
+    /// Kyrgyzstani som
+    KGS = Some(417), 2, "som", Some("tyiyn{}");
+    /// Cambodian riel
+    KHR = Some(116), 2, "riel{}", Some("sen");
+    /// Comorian franc
+    KMF = Some(174), 0, "comorian franc{}", Some("centime{}");
+    /// North Korean won
+    KPW = Some(408), 2, "north korean won", Some("chon");
     /// South Korean won
-    KRW,
+    KRW = Some(410), 0, "won", Some("jeon");
     /// Kuwaiti dinar
-    KWD,
+    KWD = Some(414), 3, "kuwaiti dinar{}", Some("fils");
+    /// Cayman Islands dollar
+    KYD = Some(136), 2, "cayman islands dollar{}", None;
     /// Kazakhstani tenge
-    KZT,
+    KZT = Some(398), 2, "tenge", Some("tiyn{}");
+    /// Lao kip
+    LAK = Some(418), 2, "kip", Some("att{}");
+    /// Lebanese pound
+    LBP = Some(422), 2, "lebanese pound{}", Some("piastre{}");
+    /// Sri Lankan rupee
+    LKR = Some(144), 2, "sri lankan rupee{}", None;
+    /// Liberian dollar
+    LRD = Some(430), 2, "liberian dollar{}", None;
+    /// Lesotho loti
+    LSL = Some(426), 2, "loti|maloti", Some("sente|lisente");
+    /// Libyan dinar
+    LYD = Some(434), 3, "libyan dinar{}", Some("dirham{}");
+    /// Moroccan dirham
+    MAD = Some(504), 2, "moroccan dirham{}", Some("centime{}");
+    /// Moldovan leu
+    MDL = Some(498), 2, "moldovan leu|moldovan lei", Some("ban|bani");
+    /// Malagasy ariary
+    MGA = Some(969), 2, "ariary", Some("iraimbilanja");
+    /// Macedonian denar
+    MKD = Some(807), 2, "macedonian denar{}", Some("deni");
+    /// Myanmar kyat
+    MMK = Some(104), 2, "kyat", Some("pya");
+    /// Mongolian tögrög
+    MNT = Some(496), 2, "tugrik{}", Some("mongo{}");
+    /// Macanese pataca
+    MOP = Some(446), 2, "pataca{}", Some("avo{}");
+    /// Mauritanian ouguiya
+    MRU = Some(929), 2, "ouguiya{}", Some("khoums");
+    /// Mauritian rupee
+    MUR = Some(480), 2, "mauritian rupee{}", None;
+    /// Maldivian rufiyaa
+    MVR = Some(462), 2, "rufiyaa", Some("laari");
+    /// Malawian kwacha
+    MWK = Some(454), 2, "malawian kwacha", Some("tambala");
     /// Mexican peso
-    MXN,
+    MXN = Some(484), 2, "mexican peso{}", Some("centavo{}");
+    /// Mexican investment unit
+    MXV = Some(979), 2, "mexican investment unit{}", None;
     /// Malaysian ringgit
-    MYR,
+    MYR = Some(458), 2, "ringgit", Some("sen");
+    /// Mozambican metical
+    MZN = Some(943), 2, "metical{}", Some("centavo{}");
+    /// Namibian dollar
+    NAD = Some(516), 2, "namibian dollar{}", None;
+    /// Nigerian naira
+    NGN = Some(566), 2, "naira", Some("kobo");
+    /// Nicaraguan córdoba
+    NIO = Some(558), 2, "cordoba{}", Some("centavo{}");
     /// Norwegian krone
-    NOK,
+    NOK = Some(578), 2, "norwegian krone{}", Some("ore");
+    /// Nepalese rupee
+    NPR = Some(524), 2, "nepalese rupee{}", Some("paisa|paise");
     /// New Zealand dollar
-    NZD,
+    NZD = Some(554), 2, "new zealand dollar{}", None;
+    /// Omani rial
+    OMR = Some(512), 3, "omani rial{}", Some("baisa{}");
+    /// Panamanian balboa
+    PAB = Some(590), 2, "balboa{}", Some("centesimo{}");
     /// Peruvian sol
-    PEN,
+    PEN = Some(604), 2, "sol|soles", Some("centimo{}");
     /// Peso
-    PESO,
+    PESO = None, 2, "peso{}", None;
+    /// Papua New Guinean kina
+    PGK = Some(598), 2, "kina", Some("toea");
     /// Philippine peso
-    PHP,
+    PHP = Some(608), 2, "peso{}", Some("centavo{}");
+    /// Pakistani rupee
+    PKR = Some(586), 2, "pakistani rupee{}", Some("paisa|paise");
     /// Polish zloty
-    PLN,
-    /// Guatamelan quetzal
-    Q,
+    PLN = Some(985), 2, "zloty{}", Some("grosz|groszy");
+    /// Paraguayan guaraní
+    PYG = Some(600), 0, "guarani{}", Some("centimo{}");
+    /// Guatemalan quetzal
+    Q = Some(320), 2, "quetzal{}", Some("centavo{}");
     /// Qatari riyal
-    QAR,
+    QAR = Some(634), 2, "qatari riyal{}", Some("dirham{}");
     /// Riyal
-    RIYAL,
+    RIYAL = None, 2, "riyal{}", None;
+    /// Romanian leu
+    RON = Some(946), 2, "romanian leu|romanian lei", Some("ban|bani");
+    /// Serbian dinar
+    RSD = Some(941), 2, "serbian dinar{}", Some("para{}");
     /// Russian ruble
-    RUB,
+    RUB = Some(643), 2, "ruble{}", Some("kopek{}");
+    /// Rwandan franc
+    RWF = Some(646), 0, "rwandan franc{}", Some("centime{}");
     /// Saudi riyal
-    SAR,
+    SAR = Some(682), 2, "saudi riyal{}", Some("halala|halalat");
+    /// Solomon Islands dollar
+    SBD = Some(90), 2, "solomon islands dollar{}", None;
+    /// Seychelles rupee
+    SCR = Some(690), 2, "seychelles rupee{}", None;
+    /// Sudanese pound
+    SDG = Some(938), 2, "sudanese pound{}", Some("piastre{}");
+    /// Swedish krona
+    SEK = Some(752), 2, "swedish krona|swedish kronor", Some("ore");
     /// Singapore dollar
-    SGD,
+    SGD = Some(702), 2, "singapore dollar{}", None;
+    /// Saint Helena pound
+    SHP = Some(654), 2, "saint helena pound{}", Some("penny|pence");
+    /// Sierra Leonean leone
+    SLE = Some(925), 2, "leone{}", None;
+    /// Somali shilling
+    SOS = Some(706), 2, "somali shilling{}", None;
+    /// Surinamese dollar
+    SRD = Some(968), 2, "surinamese dollar{}", None;
+    /// South Sudanese pound
+    SSP = Some(728), 2, "south sudanese pound{}", Some("piastre{}");
+    /// São Tomé and Príncipe dobra
+    STN = Some(930), 2, "dobra{}", Some("centimo{}");
+    /// Salvadoran colón
+    SVC = Some(222), 2, "salvadoran colon|salvadoran colones", Some("centavo{}");
+    /// Syrian pound
+    SYP = Some(760), 2, "syrian pound{}", Some("piastre{}");
+    /// Swazi lilangeni
+    SZL = Some(748), 2, "lilangeni|emalangeni", None;
     /// Thai baht
-    THB,
+    THB = Some(764), 2, "baht", Some("satang");
+    /// Tajikistani somoni
+    TJS = Some(972), 2, "somoni", Some("diram{}");
+    /// Turkmenistan manat
+    TMT = Some(934), 2, "turkmenistan manat{}", Some("tenge");
+    /// Tunisian dinar
+    TND = Some(788), 3, "tunisian dinar{}", Some("millime{}");
+    /// Tongan paʻanga
+    TOP = Some(776), 2, "pa'anga", Some("seniti");
     /// Turkish lira
-    TRY,
+    TRY = Some(949), 2, "lira{}", Some("kurus");
+    /// Trinidad and Tobago dollar
+    TTD = Some(780), 2, "trinidad and tobago dollar{}", None;
     /// Taiwan dollar
-    TWD,
+    TWD = Some(901), 2, "taiwan dollar{}", None;
+    /// Tanzanian shilling
+    TZS = Some(834), 2, "tanzanian shilling{}", None;
     /// Ukrainian hryvnia
-    UAH,
+    UAH = Some(980), 2, "hryvnia{}", Some("kopiyok{}");
+    /// Ugandan shilling
+    UGX = Some(800), 0, "ugandan shilling{}", None;
     /// US dollar
-    USD,
+    USD = Some(840), 2, "US dollar{}", None;
+    /// US dollar (next day)
+    USN = Some(997), 2, "US dollar{} (next day)", None;
+    /// Uruguayan peso in indexed units
+    UYI = Some(940), 0, "uruguayan indexed unit{}", None;
     /// Uruguayan peso
-    UYU,
-    /// Vietnamese dong
-    VND,
+    UYU = Some(858), 2, "uruguayan peso{}", Some("centesimo{}");
+    /// Uruguayan nominal wage index unit
+    UYW = Some(927), 4, "unidad previsional|unidades previsionales", None;
+    /// Uzbekistani sum
+    UZS = Some(860), 2, "uzbekistani sum{}", Some("tiyin{}");
+    /// Digital Venezuelan bolívar
+    VED = Some(926), 2, "digital bolivar|digital bolivares", Some("centimo{}");
     /// Venezuelan bolívar
-    VES,
+    VES = Some(928), 2, "bolivar|bolivares", Some("centimo{}");
+    /// Vietnamese dong
+    VND = Some(704), 0, "dong", Some("xu");
+    /// Vanuatu vatu
+    VUV = Some(548), 0, "vatu", None;
+    /// Samoan tala
+    WST = Some(882), 2, "tala", Some("sene");
+    /// Central African CFA franc
+    XAF = Some(950), 0, "CFA franc{}", Some("centime{}");
+    /// Silver
+    XAG = Some(961), 0, "troy ounce{} of silver", None;
+    /// Gold
+    XAU = Some(959), 0, "troy ounce{} of gold", None;
+    /// European composite unit
+    XBA = Some(955), 0, "european composite unit{}", None;
+    /// European monetary unit
+    XBB = Some(956), 0, "european monetary unit{}", None;
+    /// European unit of account 9
+    XBC = Some(957), 0, "european unit{} of account 9", None;
+    /// European unit of account 17
+    XBD = Some(958), 0, "european unit{} of account 17", None;
+    /// East Caribbean dollar
+    XCD = Some(951), 2, "east caribbean dollar{}", None;
+    /// Caribbean guilder
+    XCG = Some(532), 2, "caribbean guilder{}", None;
+    /// Special drawing right
+    XDR = Some(960), 0, "special drawing right{}", None;
+    /// West African CFA franc
+    XOF = Some(952), 0, "CFA franc{}", Some("centime{}");
+    /// Palladium
+    XPD = Some(964), 0, "troy ounce{} of palladium", None;
+    /// CFP franc
+    XPF = Some(953), 0, "CFP franc{}", Some("centime{}");
+    /// Platinum
+    XPT = Some(962), 0, "troy ounce{} of platinum", None;
+    /// Sucre
+    XSU = Some(994), 0, "sucre{}", None;
+    /// Code reserved for testing
+    XTS = Some(963), 0, "test unit{}", None;
+    /// ADB unit of account
+    XUA = Some(965), 0, "ADB unit{} of account", None;
+    /// No currency
+    XXX = Some(999), 0, "unit{}", None;
+    /// Yemeni rial
+    YER = Some(886), 2, "yemeni rial{}", Some("fils");
     /// South African rand
-    ZAR,
+    ZAR = Some(710), 2, "rand", None;
+    /// Zambian kwacha
+    ZMW = Some(967), 2, "zambian kwacha", Some("ngwee");
+    /// Zimbabwe gold
+    ZWG = Some(924), 2, "zimbabwe gold", None;
+}
+
+/// Writes a name of the table in the singular or the plural
+pub(crate) fn inflect(name: &str, plural_form: bool) -> String {
+    match name.split_once('|') {
+        Some((singular, plural)) => String::from(if plural_form { plural } else { singular }),
+        None => name.replace("{}", if plural_form { "s" } else { "" }),
+    }
+}
+
+/// Currencies registered at runtime, looked up by their code
+static REGISTRY: RwLock<Vec<&'static RegisteredCurrency>> = RwLock::new(Vec::new());
+
+/// Currency registered with [`CustomCurrency::register`]
+///
+/// There is one per code, which lives as long as the program. Registering
+/// the code again replaces its definition in place, for the currencies
+/// already returned as well.
+#[derive(Debug)]
+pub struct RegisteredCurrency {
+    code: String,
+    currency: RwLock<CustomCurrency>,
+}
+
+impl RegisteredCurrency {
+    /// Returns the current definition of the currency
+    fn read(&self) -> RwLockReadGuard<'_, CustomCurrency> {
+        self.currency.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// the registered currencies are unique per code
+impl PartialEq for RegisteredCurrency {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for RegisteredCurrency {}
+
+/// Currency defined at runtime, such as loyalty points or a historical
+/// currency
+///
+/// The names of its unit and subunit are given per language, with their
+/// gender for the languages where numbers agree with it. Languages without
+/// names of their own use the English ones, or the code of the currency.
+///
+/// Example:
+/// ```
+/// use num2words::{CustomCurrency, Gender, Lang, Noun, Num2Words};
+/// let peseta = CustomCurrency::new("ESP", 2)
+///     .with_names(
+///         Lang::English,
+///         Noun::new("peseta", "pesetas"),
+///         Noun::new("céntimo", "céntimos"),
+///     )
+///     .with_names(
+///         Lang::Spanish,
+///         Noun::new("peseta", "pesetas").with_gender(Gender::Feminine),
+///         Noun::new("céntimo", "céntimos"),
+///     )
+///     .register();
+/// assert_eq!(
+///     Num2Words::new(201).currency(peseta).to_words(),
+///     Ok(String::from("two hundred one pesetas"))
+/// );
+/// assert_eq!(
+///     Num2Words::new(201).lang(Lang::Spanish).currency(peseta).to_words(),
+///     Ok(String::from("doscientas una pesetas"))
+/// );
+/// assert_eq!(
+///     Num2Words::new(1).currency("ESP".parse().unwrap()).to_words(),
+///     Ok(String::from("one peseta"))
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomCurrency {
+    code: String,
+    minor_units: usize,
+    names: Vec<(Lang, Noun, Noun)>,
+}
+
+impl CustomCurrency {
+    /// Creates a currency from its code and its minor-unit exponent, i.e. the
+    /// number of decimals of its amounts
+    ///
+    /// Until names are set with [`CustomCurrency::with_names`], the code is
+    /// read in place of the name of the unit, e.g. "two PTS".
+    pub fn new<T: Into<String>>(code: T, minor_units: usize) -> Self {
+        Self {
+            code: code.into(),
+            minor_units,
+            names: vec![],
+        }
+    }
+
+    /// Sets the names of the unit and of the subunit in a language
+    ///
+    /// The names of a language apply to all of its regional variants, e.g.
+    /// those set for `Lang::French` to `Lang::French_CA`, and replace the
+    /// names set before for it.
+    pub fn with_names(mut self, lang: Lang, unit: Noun, subunit: Noun) -> Self {
+        let lang = lang.base();
+        self.names.retain(|(named, _, _)| *named != lang);
+        self.names.push((lang, unit, subunit));
+        self
+    }
+
+    /// Registers the currency, so that it can be parsed from its code, and
+    /// returns it
+    ///
+    /// A currency registered again under the same code replaces the previous
+    /// definition, both when parsing and for the `Currency` values already
+    /// returned. Each code is allocated once for the lifetime of the program,
+    /// however many times it is registered.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, CustomCurrency};
+    /// let miles = CustomCurrency::new("MILES", 0).register();
+    /// assert_eq!(CustomCurrency::new("MILES", 2).register(), miles);
+    /// assert_eq!(miles.minor_units(), 2);
+    /// assert_eq!("MILES".parse::<Currency>(), Ok(miles));
+    /// ```
+    pub fn register(self) -> Currency {
+        let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+        let custom = match registry.iter().find(|c| c.code == self.code) {
+            Some(registered) => {
+                *registered
+                    .currency
+                    .write()
+                    .unwrap_or_else(PoisonError::into_inner) = self;
+                *registered
+            }
+            None => {
+                let custom: &'static RegisteredCurrency = Box::leak(Box::new(RegisteredCurrency {
+                    code: self.code.clone(),
+                    currency: RwLock::new(self),
+                }));
+                registry.push(custom);
+                custom
+            }
+        };
+        Currency::Custom(custom)
+    }
+
+    /// Returns the names of the unit and of the subunit in a language
+    fn nouns(&self, lang: Lang) -> (Noun, Noun) {
+        let lang = lang.base();
+        self.names
+            .iter()
+            .find(|(named, _, _)| *named == lang)
+            .or_else(|| {
+                self.names
+                    .iter()
+                    .find(|(named, _, _)| *named == Lang::English)
+            })
+            .map(|(_, unit, subunit)| (unit.clone(), subunit.clone()))
+            .unwrap_or_else(|| {
+                let code = Noun::new(self.code.as_str(), self.code.as_str());
+                (code.clone(), code)
+            })
+    }
 }
 
 impl Currency {
@@ -113,179 +629,147 @@ impl Currency {
     ///
     /// Since many languages share the same work for a specific currency (like
     /// euro), it is easier and wiser for modularity to have a default value.
+    ///
+    /// A custom currency gives its English name, or else its code.
     pub fn default_string(&self, plural_form: bool) -> String {
-        match self {
-            Currency::AED => "dirham{}",
-            Currency::ARS => "argentine peso{}",
-            Currency::AUD => "australian dollar{}",
-            Currency::B => "balboa{}",
-            Currency::BRL => {
-                if plural_form {
-                    "reais"
-                } else {
-                    "real"
-                }
-            }
-            Currency::BOB => "boliviano{}",
-            Currency::C => "cordoba{}",
-            Currency::CAD => "canadian dollar{}",
-            Currency::CHF => "franc{}",
-            Currency::CLP => "chilean peso{}",
-            Currency::CNY => "yuan{}",
-            Currency::COP => "colombian peso{}",
-            Currency::CRC => {
-                if plural_form {
-                    "colones"
-                } else {
-                    "colon"
-                }
-            }
-            Currency::DINAR => "dinar{}",
-            Currency::DOLLAR => "dollar{}",
-            Currency::DZD => "algerian dinar{}",
-            Currency::EUR => "euro{}",
-            Currency::GBP => "pound{}",
-            Currency::HKD => "hong kong dollar{}",
-            Currency::IDR => "indonesian rupiah{}",
-            Currency::ILS => "new shekel{}",
-            Currency::INR => "rupee{}",
-            Currency::JPY => "yen",
-            Currency::KRW => "won",
-            Currency::KWD => "kuwaiti dinar{}",
-            Currency::KZT => "tenge{}",
-            Currency::MXN => "mexican peso{}",
-            Currency::MYR => "ringgit{}",
-            Currency::NOK => "norwegian krone{}",
-            Currency::NZD => "new zealand dollar{}",
-            Currency::PEN => {
-                if plural_form {
-                    "soles"
-                } else {
-                    "sol"
-                }
-            }
-            Currency::PESO => "peso{}",
-            Currency::PHP => "peso{}",
-            Currency::PLN => "zloty{}",
-            Currency::Q => "quetzal{}",
-            Currency::QAR => "qatari riyal{}",
-            Currency::RIYAL => "riyal{}",
-            Currency::RUB => "ruble{}",
-            Currency::SAR => "saudi riyal{}",
-            Currency::SGD => "singapore dollar{}",
-            Currency::THB => "baht{}",
-            Currency::TRY => "lira{}",
-            Currency::TWD => "taiwan dollar{}",
-            Currency::UAH => "hryvnia{}",
-            Currency::USD => "US dollar{}",
-            Currency::UYU => "uruguayan peso{}",
-            Currency::VES => {
-                if plural_form {
-                    "bolivares"
-                } else {
-                    "bolivar"
-                }
-            }
-            Currency::VND => "dong{}",
-            Currency::ZAR => "rand{}",
+        match self.custom_nouns(Lang::English) {
+            Some((unit, _)) => unit.form(plural_form).to_string(),
+            None => inflect(self.name(), plural_form),
         }
-        .replace("{}", if plural_form { "s" } else { "" })
     }
 
     /// Returns a default string representation for the cents of the currency
+    ///
+    /// `cent` is the local word for a hundredth, e.g. `cent{}` in English,
+    /// used by the currencies divided into cents.
     pub fn default_subunit_string(&self, cent: &str, plural_form: bool) -> String {
+        match self.custom_nouns(Lang::English) {
+            Some((_, subunit)) => subunit.form(plural_form).to_string(),
+            None => inflect(self.subunit().unwrap_or(cent), plural_form),
+        }
+    }
+
+    /// Returns the names of the unit and of the subunit of a custom currency
+    /// in a language
+    pub(crate) fn custom_nouns(&self, lang: Lang) -> Option<(Noun, Noun)> {
         match self {
-            Currency::DOLLAR | Currency::EUR => "cent{}",
-            Currency::GBP => {
-                if plural_form {
-                    "pence"
-                } else {
-                    "penny"
-                }
-            }
-            Currency::INR => {
-                if plural_form {
-                    "paise"
-                } else {
-                    "paisa"
-                }
-            }
-            Currency::JPY => "sen",
-            Currency::PHP => "centavo{}",
-            Currency::CHF | Currency::PEN => "centime{}",
-            Currency::AED | Currency::KWD => "fils",
-            Currency::ARS
-            | Currency::BRL
-            | Currency::CLP
-            | Currency::COP
-            | Currency::MXN
-            | Currency::BOB
-            | Currency::Q
-            | Currency::C => "centavo{}",
-            Currency::CRC | Currency::VES => "centimo{}",
-            Currency::IDR | Currency::MYR => "sen{}",
-            Currency::KRW => "jeon{}",
-            Currency::SAR => "halalat{}",
-            Currency::THB => "satang{}",
-            Currency::UAH => "kopiyok{}",
-            Currency::UYU | Currency::B => "centesimo{}",
-            Currency::VND => "xu{}",
-            _ => cent,
+            Currency::Custom(custom) => Some(custom.read().nouns(lang)),
+            _ => None,
         }
-        .replace("{}", if plural_form { "s" } else { "" })
     }
 }
 
 impl FromStr for Currency {
     type Err = ();
 
+    /// Parses the code of a currency, either alphabetic like `EUR` or
+    /// numeric like `978`, or the code of a registered custom currency
     fn from_str(currency: &str) -> Result<Self, Self::Err> {
-        match currency {
-            "AED" => Ok(Currency::AED),
-            "ARS" => Ok(Currency::ARS),
-            "AUD" => Ok(Currency::AUD),
-            "BRL" => Ok(Currency::BRL),
-            "CAD" => Ok(Currency::CAD),
-            "CHF" => Ok(Currency::CHF),
-            "CLP" => Ok(Currency::CLP),
-            "CNY" => Ok(Currency::CNY),
-            "COP" => Ok(Currency::COP),
-            "CRC" => Ok(Currency::CRC),
-            "DINAR" => Ok(Currency::DINAR),
-            "DOLLAR" => Ok(Currency::DOLLAR),
-            "DZD" => Ok(Currency::DZD),
-            "EUR" => Ok(Currency::EUR),
-            "GBP" => Ok(Currency::GBP),
-            "HKD" => Ok(Currency::HKD),
-            "IDR" => Ok(Currency::IDR),
-            "ILS" => Ok(Currency::ILS),
-            "INR" => Ok(Currency::INR),
-            "JPY" => Ok(Currency::JPY),
-            "KRW" => Ok(Currency::KRW),
-            "KWD" => Ok(Currency::KWD),
-            "KZT" => Ok(Currency::KZT),
-            "MXN" => Ok(Currency::MXN),
-            "MYR" => Ok(Currency::MYR),
-            "NOK" => Ok(Currency::NOK),
-            "NZD" => Ok(Currency::NZD),
-            "PEN" => Ok(Currency::PEN),
-            "PESO" => Ok(Currency::PESO),
-            "PHP" => Ok(Currency::PHP),
-            "PLN" => Ok(Currency::PLN),
-            "QAR" => Ok(Currency::QAR),
-            "RIYAL" => Ok(Currency::RIYAL),
-            "RUB" => Ok(Currency::RUB),
-            "SAR" => Ok(Currency::SAR),
-            "SGD" => Ok(Currency::SGD),
-            "THB" => Ok(Currency::THB),
-            "TRY" => Ok(Currency::TRY),
-            "TWD" => Ok(Currency::TWD),
-            "UAH" => Ok(Currency::UAH),
-            "USD" => Ok(Currency::USD),
-            "UYU" => Ok(Currency::UYU),
-            "VND" => Ok(Currency::VND),
-            "ZAR" => Ok(Currency::ZAR),
-            _ => Err(()),
+        let numeric = currency.parse::<u16>().ok();
+        Currency::all()
+            .iter()
+            .find(|c| match numeric {
+                // the ISO code rather than the symbol sharing it, e.g. PAB for B
+                Some(numeric) => c.numeric_code() == Some(numeric) && c.code().len() == 3,
+                None => c.code() == currency,
+            })
+            .copied()
+            .or_else(|| {
+                let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+                let custom = registry.iter().find(|c| c.code == currency)?;
+                Some(Currency::Custom(custom))
+            })
+            .ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        for currency in Currency::all() {
+            assert_eq!(Currency::from_str(currency.code()), Ok(*currency));
         }
+        assert_eq!(Currency::from_str("BOB"), Ok(Currency::BOB));
+        assert_eq!(Currency::from_str("VES"), Ok(Currency::VES));
+        assert_eq!(Currency::from_str("Q"), Ok(Currency::Q));
+        assert_eq!(Currency::from_str("978"), Ok(Currency::EUR));
+        assert_eq!(Currency::from_str("590"), Ok(Currency::PAB));
+        assert_eq!(Currency::from_str("008"), Ok(Currency::ALL));
+        assert_eq!(Currency::from_str("XYZ"), Err(()));
+        assert_eq!(Currency::from_str("123"), Err(()));
+    }
+
+    #[test]
+    fn test_iso_4217() {
+        let iso = Currency::all()
+            .iter()
+            .filter(|c| c.code().len() == 3 && ![Currency::BTC, Currency::ETH].contains(c));
+        let mut numeric_codes: Vec<_> = iso.map(|c| c.numeric_code().unwrap()).collect();
+        let count = numeric_codes.len();
+        numeric_codes.sort();
+        numeric_codes.dedup();
+        assert_eq!(numeric_codes.len(), count);
+
+        assert_eq!(Currency::DOLLAR.numeric_code(), None);
+        assert_eq!(Currency::B.numeric_code(), Currency::PAB.numeric_code());
+        assert_eq!(Currency::XOF.numeric_code(), Some(952));
+        assert_eq!(Currency::KWD.minor_units(), 3);
+        assert_eq!(Currency::CLF.minor_units(), 4);
+        assert_eq!(Currency::XAU.minor_units(), 0);
+        assert_eq!(Currency::ETH.minor_units(), 18);
+    }
+
+    #[test]
+    fn test_custom_currency() {
+        let points = CustomCurrency::new("PTS", 0)
+            .with_names(
+                Lang::English,
+                Noun::new("point", "points"),
+                Noun::new("", ""),
+            )
+            .register();
+        assert_eq!(Currency::from_str("PTS"), Ok(points));
+        assert_eq!(points.code(), "PTS");
+        assert_eq!(points.numeric_code(), None);
+        assert_eq!(points.minor_units(), 0);
+        assert_eq!(points.default_string(true), "points");
+        assert!(!Currency::all().contains(&points));
+
+        // built-in currencies cannot be shadowed
+        let euro = CustomCurrency::new("EUR", 2).register();
+        assert_eq!(Currency::from_str("EUR"), Ok(Currency::EUR));
+        assert_eq!(euro.default_string(true), "EUR");
+
+        // registering again replaces the currency in place rather than
+        // adding one
+        let Currency::Custom(first) = CustomCurrency::new("MILES", 0).register() else {
+            unreachable!()
+        };
+        let Currency::Custom(same) = CustomCurrency::new("MILES", 2).register() else {
+            unreachable!()
+        };
+        assert!(std::ptr::eq(first, same));
+        let miles = Currency::Custom(first);
+        assert_eq!(Currency::from_str("MILES"), Ok(miles));
+        assert_eq!(miles.minor_units(), 2);
+        let registry = REGISTRY.read().unwrap();
+        assert_eq!(registry.iter().filter(|c| c.code == "MILES").count(), 1);
+    }
+
+    #[test]
+    fn test_default_string() {
+        assert_eq!(Currency::DKK.default_string(true), "danish kroner");
+        assert_eq!(Currency::ZMW.default_string(false), "zambian kwacha");
+        assert_eq!(
+            Currency::TND.default_subunit_string("cent{}", true),
+            "millimes"
+        );
+        assert_eq!(
+            Currency::XCD.default_subunit_string("cent{}", true),
+            "cents"
+        );
     }
 }
@@ -1,6 +1,7 @@
 mod en;
 mod es;
 mod fr;
+mod illion;
 mod uk;
 
 pub use en::English;
@@ -9,8 +10,9 @@ pub use fr::French;
 pub use uk::Ukrainian;
 
 use crate::lang;
+use crate::noun::Noun;
 use crate::num2words::Num2Err;
-use crate::Currency;
+use crate::{Currency, Unit};
 use num_bigfloat::BigFloat;
 use std::str::FromStr;
 
@@ -21,10 +23,156 @@ pub trait Language {
     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err>;
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err>;
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err>;
+    fn to_cheque(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+        cents: Option<ChequeCents>,
+        only: bool,
+    ) -> Result<String, Num2Err>;
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err>;
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err>;
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err>;
+    fn to_scientific(&self, mantissa: BigFloat, exponent: BigFloat) -> Result<String, Num2Err>;
+    fn to_compact(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+    ) -> Result<String, Num2Err>;
+    fn to_compact_currency(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+        currency: Currency,
+    ) -> Result<String, Num2Err>;
+    fn to_time(&self, hours: u8, minutes: u8, seconds: Option<u8>) -> Result<String, Num2Err>;
+    fn to_date(&self, year: BigFloat, month: u8, day: u8) -> Result<String, Num2Err>;
+    fn to_digits(&self, groups: &[String]) -> Result<String, Num2Err>;
+    fn to_regnal(&self, num: BigFloat) -> Result<String, Num2Err>;
+    fn to_counted(&self, num: BigFloat, noun: &Noun) -> Result<String, Num2Err>;
+    fn currency_noun(&self, currency: Currency, subunit: bool) -> Result<Noun, Num2Err>;
+    fn to_currency_decimal(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        self.to_counted(num, &self.currency_noun(currency, false)?)
+    }
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err>;
+}
+
+/// Naming of the powers of a thousand above a million
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Scale {
+    /// A new name for every power of a thousand, e.g. `billion` is 10^9
+    #[default]
+    Short,
+    /// A new name for every power of a million, e.g. `billion` is 10^12
+    Long,
+}
+
+/// Writing of the cents of an amount on a cheque
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChequeCents {
+    /// Cents in words, e.g. `forty-two dollars and one cent`
+    Words,
+    /// Cents in two digits, e.g. `forty-two dollars and 01 cents`
+    Digits,
+    /// Cents as hundredths, e.g. `forty-two and 01/100 dollars`
+    Fraction,
+}
+
+/// Splits an amount into its whole part and its minor units, e.g. cents,
+/// the amount being already rounded to the minor unit
+pub(crate) fn split_minor(num: BigFloat, currency: Currency) -> (BigFloat, BigFloat) {
+    let scale = minor_scale(currency);
+    (num.int(), (num * scale).int() % scale)
+}
+
+/// Number of minor units in a major one, e.g. 100 cents in a dollar
+pub(crate) fn minor_scale(currency: Currency) -> BigFloat {
+    BigFloat::from(10).pow(&BigFloat::from(currency.minor_units() as u64))
+}
+
+/// Minor units written with all their digits as on cheques, e.g. `01`
+pub(crate) fn minor_digits(minor: BigFloat, currency: Currency) -> String {
+    let width = currency.minor_units();
+    format!("{:0width$}", minor.to_u64().unwrap_or_default())
+}
+
+/// Minor units written as a fraction of the major one, e.g. `01/100`
+pub(crate) fn minor_fraction(minor: BigFloat, currency: Currency) -> String {
+    format!(
+        "{}/1{}",
+        minor_digits(minor, currency),
+        "0".repeat(currency.minor_units())
+    )
+}
+
+/// Word set by the `conjunction:<word>` preference among the `words` a
+/// language joins the units of an amount of money to its cents with, empty for
+/// `conjunction:none`
+pub(crate) fn pick_conjunction(
+    preferences: &[String],
+    words: &[&'static str],
+) -> Option<&'static str> {
+    let word = preferences
+        .iter()
+        .rev()
+        .find_map(|v| v.strip_prefix("conjunction:"))?;
+    match word {
+        "none" => Some(""),
+        _ => words.iter().find(|w| **w == word).copied(),
+    }
+}
+
+/// Joins the units of an amount of money to its cents, e.g. "one dollar and
+/// one cent"
+pub(crate) fn join_parts(whole: &str, conjunction: &str, cents: &str) -> String {
+    match conjunction {
+        "" => format!("{whole} {cents}"),
+        _ => format!("{whole} {conjunction} {cents}"),
+    }
+}
+
+/// Splits a group of digits into chunks of the given size, the shorter chunk
+/// being the leading one (`55501` by pairs is `5`, `55`, `01`)
+pub(crate) fn digit_chunks(group: &str, size: usize) -> Vec<&str> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    let mut end = group.len() % size;
+    if end == 0 {
+        end = size;
+    }
+    while start < group.len() {
+        chunks.push(&group[start..end]);
+        start = end;
+        end += size;
+    }
+    chunks
+}
+
+/// Reads a chunk of digits either as a whole number or, when it is a single
+/// digit or has a leading zero, digit by digit
+pub(crate) fn read_digit_chunk(
+    chunk: &str,
+    to_cardinal: impl Fn(BigFloat) -> Result<String, Num2Err>,
+) -> Result<Vec<String>, Num2Err> {
+    let num = BigFloat::parse(chunk).ok_or(Num2Err::CannotConvert)?;
+    if chunk.len() > 1 && !chunk.starts_with('0') {
+        return Ok(vec![to_cardinal(num)?]);
+    }
+    chunk
+        .bytes()
+        .map(|digit| to_cardinal(BigFloat::from(digit - b'0')))
+        .collect()
 }
 
 /// Languages available in `num2words`
+///
+/// New languages and regional variants may be added over time, so a `match`
+/// on `Lang` needs a wildcard arm.
 #[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Lang {
     /// ```
     /// use num2words::{Num2Words, Lang};
@@ -34,7 +182,81 @@ pub enum Lang {
     /// );
     /// ```
     English,
-    /// French from France and Canada
+    /// English from the United States, without "and" in numbers and with
+    /// "dollars" for the US dollar
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(101).lang(Lang::English_US).to_words(),
+    ///     Ok(String::from("one hundred one"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(42)
+    ///         .lang(Lang::English_US)
+    ///         .currency(Currency::USD)
+    ///         .to_words(),
+    ///     Ok(String::from("forty-two dollars"))
+    /// );
+    /// ```
+    English_US,
+    /// English from the United Kingdom, with "pounds" for the pound sterling
+    /// and the day before the month in dates
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(2005).lang(Lang::English_GB).to_words(),
+    ///     Ok(String::from("two thousand and five"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(1.5)
+    ///         .lang(Lang::English_GB)
+    ///         .currency(Currency::GBP)
+    ///         .to_words(),
+    ///     Ok(String::from("one pound and fifty pence"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2005).lang(Lang::English_GB).date(3, 3).to_words(),
+    ///     Ok(String::from("the third of March, two thousand and five"))
+    /// );
+    /// ```
+    English_GB,
+    /// English from India, with "rupees" for the Indian rupee
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(2.5)
+    ///         .lang(Lang::English_IN)
+    ///         .currency(Currency::INR)
+    ///         .to_words(),
+    ///     Ok(String::from("two rupees and fifty paise"))
+    /// );
+    /// ```
+    English_IN,
+    /// English from Australia, with "dollars" for the Australian dollar
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42)
+    ///         .lang(Lang::English_AU)
+    ///         .currency(Currency::AUD)
+    ///         .to_words(),
+    ///     Ok(String::from("forty-two dollars"))
+    /// );
+    /// ```
+    English_AU,
+    /// English from Canada, with "dollars" for the Canadian dollar
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42)
+    ///         .lang(Lang::English_CA)
+    ///         .currency(Currency::USD)
+    ///         .to_words(),
+    ///     Ok(String::from("forty-two US dollars"))
+    /// );
+    /// ```
+    English_CA,
+    /// French from France
     /// ```
     /// use num2words::{Num2Words, Lang};
     /// assert_eq!(
@@ -61,6 +283,18 @@ pub enum Lang {
     /// );
     /// ```
     French_CH,
+    /// French from Canada, with "dollars" for the Canadian dollar
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(2.25)
+    ///         .lang(Lang::French_CA)
+    ///         .currency(Currency::CAD)
+    ///         .to_words(),
+    ///     Ok(String::from("deux dollars et vingt-cinq cents"))
+    /// );
+    /// ```
+    French_CA,
     /// ```
     /// use num2words::{Num2Words, Lang};
     /// assert_eq!(
@@ -69,6 +303,63 @@ pub enum Lang {
     /// );
     /// ```
     Spanish,
+    /// Spanish from Spain, with "coma" for the decimal separator
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(3.5).lang(Lang::Spanish_ES).to_words(),
+    ///     Ok(String::from("tres coma cinco"))
+    /// );
+    /// ```
+    Spanish_ES,
+    /// Spanish from Mexico, with "pesos" for the Mexican peso
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42)
+    ///         .lang(Lang::Spanish_MX)
+    ///         .currency(Currency::MXN)
+    ///         .to_words(),
+    ///     Ok(String::from("cuarenta y dos pesos"))
+    /// );
+    /// ```
+    Spanish_MX,
+    /// Spanish from Argentina, with "pesos" for the Argentine peso
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42)
+    ///         .lang(Lang::Spanish_AR)
+    ///         .currency(Currency::MXN)
+    ///         .to_words(),
+    ///     Ok(String::from("cuarenta y dos pesos mexicanos"))
+    /// );
+    /// ```
+    Spanish_AR,
+    /// Spanish from Colombia, with "pesos" for the Colombian peso
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42)
+    ///         .lang(Lang::Spanish_CO)
+    ///         .currency(Currency::COP)
+    ///         .to_words(),
+    ///     Ok(String::from("cuarenta y dos pesos"))
+    /// );
+    /// ```
+    Spanish_CO,
+    /// Spanish from Chile, with "pesos" for the Chilean peso
+    /// ```
+    /// use num2words::{Currency, Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42)
+    ///         .lang(Lang::Spanish_CL)
+    ///         .currency(Currency::CLP)
+    ///         .to_words(),
+    ///     Ok(String::from("cuarenta y dos pesos"))
+    /// );
+    /// ```
+    Spanish_CL,
     /// ```
     /// use num2words::{Num2Words, Lang};
     /// assert_eq!(
@@ -79,26 +370,69 @@ pub enum Lang {
     Ukrainian,
 }
 
+impl Lang {
+    /// Returns the language without its regional variant, e.g. French for
+    /// French_BE
+    pub(crate) fn base(self) -> Lang {
+        match self {
+            Lang::English_US
+            | Lang::English_GB
+            | Lang::English_IN
+            | Lang::English_AU
+            | Lang::English_CA => Lang::English,
+            Lang::Spanish_ES
+            | Lang::Spanish_MX
+            | Lang::Spanish_AR
+            | Lang::Spanish_CO
+            | Lang::Spanish_CL => Lang::Spanish,
+            Lang::French_BE | Lang::French_CH | Lang::French_CA => Lang::French,
+            lang => lang,
+        }
+    }
+}
+
 impl FromStr for Lang {
     type Err = ();
 
     /// Parses a string to return a value of this type
     ///
-    /// | Locale    | Lang              | 42            |
-    /// | --------- | ----------------- | ------------- |
-    /// | `en`      | `Lang::English`   | forty-two     |
-    /// | `es`      | `Lang::Spanish`   | cuarenta y dos|
-    /// | `fr`      | `Lang::French`    | quarante-deux |
-    /// | `fr_BE`   | `Lang::French_BE` | quarante-deux |
-    /// | `fr_CH`   | `Lang::French_CH` | quarante-deux |
-    /// | `uk`      | `Lang::Ukrainian` | сорок два     |
+    /// | Locale  | Lang               | 42             |
+    /// | ------- | ------------------ | -------------- |
+    /// | `en`    | `Lang::English`    | forty-two      |
+    /// | `en_US` | `Lang::English_US` | forty-two      |
+    /// | `en_GB` | `Lang::English_GB` | forty-two      |
+    /// | `en_IN` | `Lang::English_IN` | forty-two      |
+    /// | `en_AU` | `Lang::English_AU` | forty-two      |
+    /// | `en_CA` | `Lang::English_CA` | forty-two      |
+    /// | `es`    | `Lang::Spanish`    | cuarenta y dos |
+    /// | `es_ES` | `Lang::Spanish_ES` | cuarenta y dos |
+    /// | `es_MX` | `Lang::Spanish_MX` | cuarenta y dos |
+    /// | `es_AR` | `Lang::Spanish_AR` | cuarenta y dos |
+    /// | `es_CO` | `Lang::Spanish_CO` | cuarenta y dos |
+    /// | `es_CL` | `Lang::Spanish_CL` | cuarenta y dos |
+    /// | `fr`    | `Lang::French`     | quarante-deux  |
+    /// | `fr_BE` | `Lang::French_BE`  | quarante-deux  |
+    /// | `fr_CH` | `Lang::French_CH`  | quarante-deux  |
+    /// | `fr_CA` | `Lang::French_CA`  | quarante-deux  |
+    /// | `uk`    | `Lang::Ukrainian`  | сорок два      |
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
             "en" => Ok(Self::English),
+            "en_US" => Ok(Self::English_US),
+            "en_GB" => Ok(Self::English_GB),
+            "en_IN" => Ok(Self::English_IN),
+            "en_AU" => Ok(Self::English_AU),
+            "en_CA" => Ok(Self::English_CA),
             "es" => Ok(Self::Spanish),
+            "es_ES" => Ok(Self::Spanish_ES),
+            "es_MX" => Ok(Self::Spanish_MX),
+            "es_AR" => Ok(Self::Spanish_AR),
+            "es_CO" => Ok(Self::Spanish_CO),
+            "es_CL" => Ok(Self::Spanish_CL),
             "fr" => Ok(Self::French),
             "fr_BE" => Ok(Self::French_BE),
             "fr_CH" => Ok(Self::French_CH),
+            "fr_CA" => Ok(Self::French_CA),
             "uk" => Ok(Self::Ukrainian),
             _ => Err(()),
         }
@@ -106,63 +440,146 @@ impl FromStr for Lang {
 }
 
 pub fn to_language(lang: Lang, preferences: Vec<String>) -> Box<dyn Language> {
+    let digit_group = preferences
+        .iter()
+        .rev()
+        .find_map(|v| match v.as_str() {
+            "pairs" => Some(2),
+            "triples" => Some(3),
+            _ => None,
+        })
+        .unwrap_or(1);
+    let clock_24h = preferences
+        .iter()
+        .rev()
+        .find(|v| ["12h", "24h"].contains(&v.as_str()))
+        .map(|v| v == "24h");
+    let prefer_half = preferences.iter().any(|v| v == "half");
+    let both_parts = preferences.iter().any(|v| v == "both");
+    let scale = preferences
+        .iter()
+        .rev()
+        .find(|v| ["short", "long"].contains(&v.as_str()))
+        .map(|v| match v.as_str() {
+            "long" => Scale::Long,
+            _ => Scale::Short,
+        });
+
     match lang {
-        Lang::English => {
+        Lang::English
+        | Lang::English_US
+        | Lang::English_GB
+        | Lang::English_IN
+        | Lang::English_AU
+        | Lang::English_CA => {
             let last = preferences
                 .iter()
                 .rev()
                 .find(|v| ["oh", "nil"].contains(&v.as_str()));
 
-            if let Some(v) = last {
-                return Box::new(lang::English::new(v == "oh", v == "nil"));
-            }
-
-            Box::new(lang::English::new(false, false))
-        }
-        Lang::French => {
-            let feminine = preferences
+            let english = match last {
+                Some(v) => lang::English::new(v == "oh", v == "nil"),
+                None => lang::English::new(false, false),
+            };
+            let prefer_double = preferences.iter().any(|v| v == "double");
+            let prefer_p = preferences.iter().any(|v| v == "p");
+            let region = match lang {
+                Lang::English_US => Some(lang::en::RegionEnglish::US),
+                Lang::English_GB => Some(lang::en::RegionEnglish::GB),
+                Lang::English_IN => Some(lang::en::RegionEnglish::IN),
+                Lang::English_AU => Some(lang::en::RegionEnglish::AU),
+                Lang::English_CA => Some(lang::en::RegionEnglish::CA),
+                _ => None,
+            };
+            let day_first = preferences
                 .iter()
-                .any(|v| ["feminine", "feminin", "féminin", "f"].contains(&v.as_str()));
-            let reformed = preferences.iter().any(|v: &String| {
-                ["reformed", "1990", "rectifié", "rectification"].contains(&v.as_str())
-            });
+                .rev()
+                .find(|v| ["mdy", "dmy"].contains(&v.as_str()))
+                .map(|v| v == "dmy")
+                .or(region.map(lang::en::RegionEnglish::day_first))
+                .unwrap_or(false);
 
-            Box::new(lang::French::new(
-                feminine,
-                reformed,
-                lang::fr::RegionFrench::FR,
-            ))
+            Box::new(
+                english
+                    .with_24h(clock_24h.unwrap_or(false))
+                    .with_day_first(day_first)
+                    .with_digit_group(digit_group)
+                    .with_double(prefer_double)
+                    .with_half(prefer_half)
+                    .with_scale(scale.unwrap_or(Scale::Short))
+                    .with_currency_parts(
+                        both_parts,
+                        pick_conjunction(&preferences, &["and", "with"]),
+                    )
+                    .with_region(region)
+                    .with_p(prefer_p),
+            )
         }
-        Lang::French_BE => {
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA => {
             let feminine = preferences
                 .iter()
                 .any(|v| ["feminine", "feminin", "féminin", "f"].contains(&v.as_str()));
             let reformed = preferences.iter().any(|v: &String| {
                 ["reformed", "1990", "rectifié", "rectification"].contains(&v.as_str())
             });
-
-            Box::new(lang::French::new(
-                feminine,
-                reformed,
-                lang::fr::RegionFrench::BE,
-            ))
-        }
-        Lang::French_CH => {
-            let feminine = preferences
+            let virgule = preferences
                 .iter()
-                .any(|v| ["feminine", "feminin", "féminin", "f"].contains(&v.as_str()));
-            let reformed = preferences.iter().any(|v: &String| {
-                ["reformed", "1990", "rectifié", "rectification"].contains(&v.as_str())
-            });
+                .rev()
+                .find(|v| ["virgule", "point"].contains(&v.as_str()))
+                .is_some_and(|v| v == "virgule");
+            let region = match lang {
+                Lang::French_BE => lang::fr::RegionFrench::BE,
+                Lang::French_CH => lang::fr::RegionFrench::CH,
+                Lang::French_CA => lang::fr::RegionFrench::CA,
+                _ => lang::fr::RegionFrench::FR,
+            };
+            // the region's words for seventy to ninety, unless preferred otherwise,
+            // e.g. "octante" or "quatre-vingts" in some Swiss cantons
+            let mut tens = region.tens();
+            for preference in &preferences {
+                match preference.as_str() {
+                    "septante" => tens.septante = true,
+                    "soixante-dix" => tens.septante = false,
+                    "huitante" => tens.eighty = lang::fr::Eighty::Huitante,
+                    "octante" => tens.eighty = lang::fr::Eighty::Octante,
+                    "quatre-vingts" => tens.eighty = lang::fr::Eighty::QuatreVingts,
+                    "nonante" => tens.nonante = true,
+                    "quatre-vingt-dix" => tens.nonante = false,
+                    _ => (),
+                }
+            }
+            let prefer_rappen = preferences.iter().any(|v| v == "rappen");
 
-            Box::new(lang::French::new(
-                feminine,
-                reformed,
-                lang::fr::RegionFrench::CH,
-            ))
+            Box::new(
+                lang::French::new(feminine, reformed, region)
+                    .with_tens(tens)
+                    .with_rappen(prefer_rappen)
+                    .with_virgule(virgule)
+                    .with_24h(clock_24h.unwrap_or(true))
+                    .with_digit_group(digit_group)
+                    .with_half(prefer_half)
+                    .with_scale(scale.unwrap_or(Scale::Long))
+                    .with_currency_parts(
+                        both_parts,
+                        pick_conjunction(&preferences, &["et", "avec"]),
+                    ),
+            )
         }
-        Lang::Spanish => {
-            use es::{DecimalChar, NegativeFlavour};
+        Lang::Spanish
+        | Lang::Spanish_ES
+        | Lang::Spanish_MX
+        | Lang::Spanish_AR
+        | Lang::Spanish_CO
+        | Lang::Spanish_CL => {
+            use es::{DecimalChar, NegativeFlavour, RegionSpanish};
+            let region = match lang {
+                Lang::Spanish_ES => Some(RegionSpanish::ES),
+                Lang::Spanish_MX => Some(RegionSpanish::MX),
+                Lang::Spanish_AR => Some(RegionSpanish::AR),
+                Lang::Spanish_CO => Some(RegionSpanish::CO),
+                Lang::Spanish_CL => Some(RegionSpanish::CL),
+                _ => None,
+            };
             let neg_flavour = preferences
                 .iter()
                 .find_map(|v| NegativeFlavour::from_str(v).ok())
@@ -173,6 +590,7 @@ pub fn to_language(lang: Lang, preferences: Vec<String>) -> Box<dyn Language> {
             let decimal_char = preferences
                 .iter()
                 .find_map(|v| DecimalChar::from_str(v).ok())
+                .or(region.map(RegionSpanish::decimal_char))
                 .unwrap_or_default();
             let feminine = preferences.iter().any(|v| {
                 ["f", "femenino", "feminine"]
@@ -185,7 +603,13 @@ pub fn to_language(lang: Lang, preferences: Vec<String>) -> Box<dyn Language> {
             let lang = lang::Spanish::new(decimal_char, feminine)
                 .with_plural(plural)
                 .with_veinte(prefer_veinte)
-                .with_neg_flavour(neg_flavour);
+                .with_neg_flavour(neg_flavour)
+                .with_24h(clock_24h.unwrap_or(false))
+                .with_digit_group(digit_group)
+                .with_half(prefer_half)
+                .with_scale(scale.unwrap_or(Scale::Long))
+                .with_currency_parts(both_parts, pick_conjunction(&preferences, &["con", "y"]))
+                .with_region(region);
             Box::new(lang)
         }
         Lang::Ukrainian => {
@@ -204,7 +628,14 @@ pub fn to_language(lang: Lang, preferences: Vec<String>) -> Box<dyn Language> {
                 .rev()
                 .find_map(|d| d.parse().ok())
                 .unwrap_or_default();
-            Box::new(lang::Ukrainian::new(gender, number, declension))
+            Box::new(
+                lang::Ukrainian::new(gender, number, declension)
+                    .with_24h(clock_24h.unwrap_or(true))
+                    .with_digit_group(digit_group)
+                    .with_half(prefer_half)
+                    .with_scale(scale.unwrap_or(Scale::Short))
+                    .with_currency_parts(both_parts, pick_conjunction(&preferences, &["і", "та"])),
+            )
         }
     }
 }
@@ -1,15 +1,67 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::currency::inflect;
+use crate::lang::illion::illion_stem;
+use crate::lang::{
+    digit_chunks, join_parts, minor_digits, minor_fraction, read_digit_chunk, split_minor,
+    ChequeCents, Scale,
+};
+use crate::noun::Noun;
+use crate::{num2words::Num2Err, Currency, Language, Unit};
 use num_bigfloat::BigFloat;
 
 pub struct English {
     prefer_oh: bool,
     prefer_nil: bool,
+    prefer_24h: bool,
+    day_first: bool,
+    digit_group: usize,
+    prefer_double: bool,
+    prefer_half: bool,
+    scale: Scale,
+    both_parts: bool,
+    conjunction: Option<&'static str>,
+    region: Option<RegionEnglish>,
+    prefer_p: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RegionEnglish {
+    US,
+    GB,
+    IN,
+    AU,
+    CA,
+}
+
+impl RegionEnglish {
+    /// Whether dates are written with the day before the month in the
+    /// country, e.g. "the third of March" in the United Kingdom
+    pub fn day_first(self) -> bool {
+        matches!(
+            self,
+            RegionEnglish::GB | RegionEnglish::IN | RegionEnglish::AU
+        )
+    }
 }
 
 const UNITS: [&str; 9] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
 const TENS: [&str; 9] = [
     "ten", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
 ];
@@ -54,20 +106,174 @@ const MEGAS: [&str; 21] = [
     "vigintillion",
 ];
 
+/// Name of the n-th "-illion", systematic beyond the vigintillion
+fn illion(n: usize) -> String {
+    match MEGAS.get(n) {
+        Some(mega) => String::from(*mega),
+        None => format!("{}illion", illion_stem(n)),
+    }
+}
+
 impl English {
     pub fn new(prefer_oh: bool, prefer_nil: bool) -> Self {
         Self {
             prefer_oh,
             prefer_nil,
+            prefer_24h: false,
+            day_first: false,
+            digit_group: 1,
+            prefer_double: false,
+            prefer_half: false,
+            scale: Scale::Short,
+            both_parts: false,
+            conjunction: None,
+            region: None,
+            prefer_p: false,
+        }
+    }
+
+    pub fn with_24h(self, prefer_24h: bool) -> Self {
+        Self { prefer_24h, ..self }
+    }
+
+    pub fn with_day_first(self, day_first: bool) -> Self {
+        Self { day_first, ..self }
+    }
+
+    pub fn with_digit_group(self, digit_group: usize) -> Self {
+        Self {
+            digit_group,
+            ..self
+        }
+    }
+
+    pub fn with_double(self, prefer_double: bool) -> Self {
+        Self {
+            prefer_double,
+            ..self
+        }
+    }
+
+    pub fn with_half(self, prefer_half: bool) -> Self {
+        Self {
+            prefer_half,
+            ..self
+        }
+    }
+
+    pub fn with_scale(self, scale: Scale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn with_currency_parts(self, both_parts: bool, conjunction: Option<&'static str>) -> Self {
+        Self {
+            both_parts,
+            conjunction,
+            ..self
         }
     }
 
+    pub fn with_region(self, region: Option<RegionEnglish>) -> Self {
+        Self { region, ..self }
+    }
+
+    pub fn with_p(self, prefer_p: bool) -> Self {
+        Self { prefer_p, ..self }
+    }
+
+    /// Name of the given power of a thousand, e.g. "thousand million" for
+    /// the third one on the long scale
+    fn mega(&self, power: usize) -> String {
+        match (self.scale, power % 2) {
+            (Scale::Short, _) => illion(power - 1),
+            (Scale::Long, _) if power == 1 => String::from(MEGAS[0]),
+            (Scale::Long, 0) => illion(power / 2),
+            (Scale::Long, _) => format!("{} {}", MEGAS[0], illion(power / 2)),
+        }
+    }
+
+    fn unit_noun(&self, unit: Unit) -> Noun {
+        let (singular, plural) = match unit {
+            Unit::Kilometre => ("kilometre", "kilometres"),
+            Unit::Metre => ("metre", "metres"),
+            Unit::Centimetre => ("centimetre", "centimetres"),
+            Unit::Millimetre => ("millimetre", "millimetres"),
+            Unit::Mile => ("mile", "miles"),
+            Unit::Foot => ("foot", "feet"),
+            Unit::Inch => ("inch", "inches"),
+            Unit::Tonne => ("tonne", "tonnes"),
+            Unit::Kilogram => ("kilogram", "kilograms"),
+            Unit::Gram => ("gram", "grams"),
+            Unit::Pound => ("pound", "pounds"),
+            Unit::Litre => ("litre", "litres"),
+            Unit::Millilitre => ("millilitre", "millilitres"),
+            Unit::Celsius => ("degree Celsius", "degrees Celsius"),
+            Unit::Fahrenheit => ("degree Fahrenheit", "degrees Fahrenheit"),
+            Unit::KilometrePerHour => ("kilometre per hour", "kilometres per hour"),
+            Unit::MilePerHour => ("mile per hour", "miles per hour"),
+        };
+        Noun::new(singular, plural)
+    }
+
+    // Reads runs of the same digit as "double" or "triple", e.g. "double five"
+    fn collapse_digits(&self, group: &str) -> Result<Vec<String>, Num2Err> {
+        let mut words = vec![];
+        let bytes = group.as_bytes();
+        let mut start = 0;
+        while start < bytes.len() {
+            let mut run = bytes[start..]
+                .iter()
+                .take_while(|&&b| b == bytes[start])
+                .count();
+            let digit = self.int_to_cardinal(BigFloat::from(bytes[start] - b'0'))?;
+            start += run;
+            while run > 0 {
+                // runs of four are two doubles rather than a triple and a single
+                let take = match run {
+                    1 => 1,
+                    2 | 4 => 2,
+                    _ => 3,
+                };
+                words.push(match take {
+                    1 => digit.clone(),
+                    2 => format!("double {digit}"),
+                    _ => format!("triple {digit}"),
+                });
+                run -= take;
+            }
+        }
+        Ok(words)
+    }
+
     fn currencies(&self, currency: Currency, plural_form: bool) -> String {
-        currency.default_string(plural_form)
+        use RegionEnglish::*;
+        // the local currency goes without its country, e.g. "dollars" in the
+        // United States but "US dollars" elsewhere
+        let name = match (self.region, currency) {
+            (Some(US), Currency::USD) | (Some(AU), Currency::AUD) | (Some(CA), Currency::CAD) => {
+                "dollar{}"
+            }
+            (Some(GB), Currency::GBP) => "pound{}",
+            (Some(IN), Currency::INR) => "rupee{}",
+            (Some(_), Currency::GBP) => "pound{} sterling",
+            _ => return currency.default_string(plural_form),
+        };
+        inflect(name, plural_form)
     }
 
     fn cents(&self, currency: Currency, plural_form: bool) -> String {
-        currency.default_subunit_string("cent{}", plural_form)
+        match (self.region, currency) {
+            (Some(RegionEnglish::GB), Currency::GBP) if self.prefer_p => String::from("p"),
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+    }
+
+    fn whole_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} {}",
+            self.int_to_cardinal(num)?,
+            self.currencies(currency, num != BigFloat::from(1))
+        ))
     }
 
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
@@ -103,7 +309,8 @@ impl English {
 
         // iterate over thousands
         let mut first_elem = true;
-        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+        let triplets = self.split_thousands(num);
+        for (i, triplet) in triplets.iter().enumerate().rev() {
             let hundreds = (triplet / 100 % 10) as usize;
             let tens = (triplet / 10 % 10) as usize;
             let units = (triplet % 10) as usize;
@@ -114,11 +321,18 @@ impl English {
             }
 
             if tens != 0 || units != 0 {
-                if i == 0 && !first_elem {
+                let and = match self.region {
+                    None => i == 0 && !first_elem,
+                    Some(RegionEnglish::US) | Some(RegionEnglish::CA) => false,
+                    // British usage puts "and" before the tens of any hundred,
+                    // e.g. "one hundred and one thousand" and "two thousand
+                    // and five"
+                    Some(_) => hundreds > 0 || (i == 0 && triplets[1..].iter().any(|&t| t != 0)),
+                };
+                if and {
                     words.push(String::from("and"));
-                } else {
-                    first_elem = false;
                 }
+                first_elem = false;
 
                 match tens {
                     0 => {
@@ -141,10 +355,11 @@ impl English {
             }
 
             if i != 0 && triplet != &0 {
-                if i > MEGAS.len() {
-                    return Err(Num2Err::CannotConvert);
-                }
-                words.push(String::from(MEGAS[i - 1]));
+                words.push(match self.scale {
+                    // the millions follow, e.g. "five thousand one million"
+                    Scale::Long if i % 2 == 1 && triplets[i - 1] != 0 => String::from(MEGAS[0]),
+                    _ => self.mega(i),
+                });
             }
         }
 
@@ -174,6 +389,31 @@ impl English {
         }
         Ok(words.join(" "))
     }
+
+    fn fraction_denominator(
+        &self,
+        denominator: BigFloat,
+        plural_form: bool,
+    ) -> Result<String, Num2Err> {
+        let word = match denominator.to_u64() {
+            Some(2) => String::from(if plural_form { "halves" } else { "half" }),
+            Some(4) => String::from("quarter"),
+            _ => {
+                let ordinal = self.to_ordinal(denominator)?;
+                // e.g. one hundredth => three hundredths
+                match ordinal.strip_prefix("one ") {
+                    Some(rest) if !rest.contains(' ') => String::from(rest),
+                    _ => ordinal,
+                }
+            }
+        };
+
+        if plural_form && !word.ends_with('s') {
+            Ok(format!("{word}s"))
+        } else {
+            Ok(word)
+        }
+    }
 }
 
 impl Language for English {
@@ -247,11 +487,12 @@ impl Language for English {
     }
 
     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
-        let tail = (num % BigFloat::from(100)).to_u64().unwrap();
+        let num = num.to_u128().ok_or(Num2Err::CannotConvert)?;
+        let tail = num % 100;
         let last = tail % 10;
         Ok(format!(
             "{}{}",
-            num.to_u128().unwrap(),
+            num,
             match (tail / 10 != 1, last) {
                 (true, 1) => "st",
                 (true, 2) => "nd",
@@ -277,8 +518,8 @@ impl Language for English {
         let bf_100 = BigFloat::from(100);
 
         let (high, low) = (
-            (num / bf_100).to_i64().unwrap(),
-            (num % bf_100).to_i64().unwrap(),
+            (num / bf_100).to_i64().ok_or(Num2Err::CannotConvert)?,
+            (num % bf_100).to_i64().ok_or(Num2Err::CannotConvert)?,
         );
         let year_word = if high == 0 || (high % 10 == 0 && low < 10) || high >= 100 {
             // if year is 00XX, X00X, or beyond 9999, go cardinal
@@ -300,34 +541,276 @@ impl Language for English {
     }
 
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // the "both" preference reads the units and the cents even when zero
+        let both = self.both_parts && currency.minor_units() > 0;
         if num.is_inf() {
             Ok(format!(
                 "{}an infinity of {}",
                 if num.is_negative() { "minus " } else { "" },
                 self.currencies(currency, true)
             ))
-        } else if num.frac().is_zero() {
-            let words = self.int_to_cardinal(num)?;
-            Ok(format!(
-                "{} {}",
-                words,
-                self.currencies(currency, num != BigFloat::from(1))
-            ))
+        } else if num.frac().is_zero() && !both {
+            self.whole_currency(num, currency)
         } else {
-            let integral_part = num.int();
-            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let (integral_part, cents_nb) = split_minor(num, currency);
             let cents_words = self.int_to_cardinal(cents_nb)?;
             let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
-            let integral_word = self.to_currency(integral_part, currency)?;
+            let integral_word = self.whole_currency(integral_part, currency)?;
 
-            if cents_nb.is_zero() {
+            if cents_nb.is_zero() && !both {
                 Ok(integral_word)
-            } else if integral_part.is_zero() {
+            } else if integral_part.is_zero() && !both {
                 Ok(format!("{cents_words} {cents_suffix}"))
             } else {
-                Ok(format!("{integral_word} and {cents_words} {cents_suffix}"))
+                Ok(join_parts(
+                    &integral_word,
+                    self.conjunction.unwrap_or("and"),
+                    &format!("{cents_words} {cents_suffix}"),
+                ))
+            }
+        }
+    }
+
+    fn to_cheque(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+        cents: Option<ChequeCents>,
+        only: bool,
+    ) -> Result<String, Num2Err> {
+        let (whole, cents_nb) = split_minor(num, currency);
+        let mut words = match cents.unwrap_or(ChequeCents::Fraction) {
+            ChequeCents::Words => self.to_currency(num, currency)?,
+            ChequeCents::Digits => format!(
+                "{} and {} {}",
+                self.to_currency(whole, currency)?,
+                minor_digits(cents_nb, currency),
+                self.cents(currency, true)
+            ),
+            ChequeCents::Fraction => format!(
+                "{} and {} {}",
+                self.int_to_cardinal(whole)?,
+                minor_fraction(cents_nb, currency),
+                self.currencies(currency, true)
+            ),
+        };
+        if only {
+            words.push_str(" only");
+        }
+        Ok(words)
+    }
+
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        let mut numerator = numerator;
+        if numerator.is_negative() {
+            words.push(String::from("minus"));
+            numerator = -numerator;
+        }
+
+        let whole = (numerator / denominator).int();
+        let rest = numerator % denominator;
+
+        if !whole.is_zero() {
+            words.push(self.int_to_cardinal(whole)?);
+            if rest.is_zero() {
+                return Ok(words.join(" "));
+            }
+            words.push(String::from("and"));
+        }
+
+        let one = BigFloat::from(1);
+        if !whole.is_zero() && rest == one && [2, 4].contains(&denominator.to_u64().unwrap_or(0)) {
+            // case 2 1/2 => two and a half
+            words.push(String::from("a"));
+        } else {
+            words.push(self.int_to_cardinal(rest)?);
+        }
+        words.push(self.fraction_denominator(denominator, rest != one)?);
+
+        Ok(words.join(" "))
+    }
+
+    fn to_compact(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+    ) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if approximate {
+            words.push(String::from("about"));
+        }
+        if scale == 0 {
+            words.push(self.to_cardinal(leading)?);
+            return Ok(words.join(" "));
+        }
+        let mega = self.mega(scale);
+
+        // e.g. "two and a half million"
+        if self.prefer_half && leading.frac().abs() == BigFloat::from(0.5) {
+            words.push(self.to_cardinal(leading.int())?);
+            words.push(format!("and a half {mega}"));
+        } else {
+            words.push(self.to_cardinal(leading)?);
+            words.push(mega);
+        }
+        Ok(words.join(" "))
+    }
+
+    fn to_compact_currency(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        // the currency follows the scale word, e.g. "two point five million dollars"
+        let plural = scale > 0 || leading.abs() != BigFloat::from(1);
+        Ok(format!(
+            "{} {}",
+            self.to_compact(leading, scale, approximate)?,
+            self.currency_noun(currency, false)?.form(plural)
+        ))
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} percent", self.to_cardinal(num)?))
+    }
+
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} per mille", self.to_cardinal(num)?))
+    }
+
+    fn to_scientific(&self, mantissa: BigFloat, exponent: BigFloat) -> Result<String, Num2Err> {
+        // the power is read as an ordinal, e.g. "ten to the minus nineteenth"
+        let power = if exponent.is_negative() {
+            format!("minus {}", self.to_ordinal(-exponent)?)
+        } else {
+            self.to_ordinal(exponent)?
+        };
+        Ok(format!(
+            "{} times ten to the {power}",
+            self.to_cardinal(mantissa)?
+        ))
+    }
+
+    fn to_time(&self, hours: u8, minutes: u8, seconds: Option<u8>) -> Result<String, Num2Err> {
+        let mut words = vec![];
+
+        let (hours, suffix) = if self.prefer_24h {
+            (hours, None)
+        } else {
+            let suffix = if hours < 12 { "am" } else { "pm" };
+            (
+                match hours % 12 {
+                    0 => 12,
+                    h => h,
+                },
+                Some(suffix),
+            )
+        };
+        if self.prefer_24h && hours < 10 {
+            // hours are padded in the 24-hour clock, as in "oh nine thirty", midnight taking the
+            // preferred zero, as in "zero zero thirty"
+            words.push(match hours {
+                0 => self.int_to_cardinal(BigFloat::from(0))?,
+                _ => String::from("oh"),
+            });
+        }
+        if !(self.prefer_24h && hours == 0 && minutes == 0) {
+            words.push(self.int_to_cardinal(BigFloat::from(hours))?);
+        }
+
+        if minutes == 0 {
+            if self.prefer_24h && hours == 0 {
+                words.push(String::from("hundred hours"));
+            } else if self.prefer_24h {
+                words.push(String::from("hundred"));
+            }
+        } else {
+            if minutes < 10 {
+                // minutes are always padded, as in "two oh five"
+                let padding = English::new(true, false);
+                words.push(padding.int_to_cardinal(BigFloat::from(0))?);
+            }
+            words.push(self.int_to_cardinal(BigFloat::from(minutes))?);
+        }
+
+        if let Some(suffix) = suffix {
+            words.push(String::from(suffix));
+        }
+
+        if let Some(seconds) = seconds.filter(|&s| s != 0) {
+            words.push(format!(
+                "and {} second{}",
+                self.int_to_cardinal(BigFloat::from(seconds))?,
+                if seconds == 1 { "" } else { "s" }
+            ));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_date(&self, year: BigFloat, month: u8, day: u8) -> Result<String, Num2Err> {
+        let month = MONTHS[month as usize - 1];
+        let day = self.to_ordinal(BigFloat::from(day))?;
+        let year = self.to_year(year)?;
+
+        Ok(if self.day_first {
+            format!("the {day} of {month}, {year}")
+        } else {
+            format!("{month} {day}, {year}")
+        })
+    }
+
+    fn to_regnal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("the {}", self.to_ordinal(num)?))
+    }
+
+    fn to_counted(&self, num: BigFloat, noun: &Noun) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} {}",
+            self.to_cardinal(num)?,
+            noun.form(num.abs() != BigFloat::from(1))
+        ))
+    }
+
+    fn currency_noun(&self, currency: Currency, subunit: bool) -> Result<Noun, Num2Err> {
+        let name = |plural_form| match subunit {
+            true => self.cents(currency, plural_form),
+            false => self.currencies(currency, plural_form),
+        };
+        Ok(Noun::new(name(false), name(true)))
+    }
+
+    fn to_currency_decimal(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let words = self.to_counted(num, &self.currency_noun(currency, false)?)?;
+        // amounts below one keep their zero, e.g. "zero point five dollars"
+        if num.int().is_zero() && !num.frac().is_zero() {
+            let minus = if num.is_negative() { "minus " } else { "" };
+            Ok(format!("{minus}zero {words}"))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.to_counted(num, &self.unit_noun(unit))
+    }
+
+    fn to_digits(&self, groups: &[String]) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        for group in groups {
+            if self.prefer_double && self.digit_group == 1 {
+                words.extend(self.collapse_digits(group)?);
+                continue;
+            }
+            for chunk in digit_chunks(group, self.digit_group) {
+                words.extend(read_digit_chunk(chunk, |n| self.int_to_cardinal(n))?);
             }
         }
+        Ok(words.join(" "))
     }
 }
 
@@ -447,6 +930,13 @@ mod tests {
                 .to_words(),
             Err(num2words::Num2Err::FloatingOrdinal)
         );
+        assert_eq!(
+            Num2Words::new(1e50)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
     }
 
     #[test]
@@ -518,94 +1008,885 @@ mod tests {
                 .to_words(),
             Ok(String::from("zero dollars"))
         );
+        // these names are the same in the plural
+        let five = |currency: Currency| {
+            Num2Words::new(5.05)
+                .lang(Lang::English)
+                .currency(currency)
+                .to_words()
+        };
+        assert_eq!(
+            five(Currency::CNY),
+            Ok(String::from("five yuan and five fen"))
+        );
+        assert_eq!(five(Currency::JPY), Ok(String::from("five yen")));
+        assert_eq!(five(Currency::KRW), Ok(String::from("five won")));
+        assert_eq!(
+            five(Currency::ZAR),
+            Ok(String::from("five rand and five cents"))
+        );
+        assert_eq!(
+            five(Currency::THB),
+            Ok(String::from("five baht and five satang"))
+        );
+        assert_eq!(
+            five(Currency::MMK),
+            Ok(String::from("five kyat and five pya"))
+        );
+        assert_eq!(five(Currency::XOF), Ok(String::from("five CFA francs")));
     }
 
     #[test]
-    fn test_year() {
+    fn test_fraction() {
         assert_eq!(
-            Num2Words::new(1990).lang(Lang::English).year().to_words(),
-            Ok(String::from("nineteen ninety"))
+            Num2Words::new(3).lang(Lang::English).fraction(4).to_words(),
+            Ok(String::from("three quarters"))
         );
         assert_eq!(
-            Num2Words::new(5555).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty-five fifty-five"))
+            Num2Words::new(5).lang(Lang::English).fraction(8).to_words(),
+            Ok(String::from("five eighths"))
         );
         assert_eq!(
-            Num2Words::new(2022).lang(Lang::English).year().to_words(),
-            Ok(String::from("twenty twenty-two"))
+            Num2Words::new(1).lang(Lang::English).fraction(2).to_words(),
+            Ok(String::from("one half"))
         );
         assert_eq!(
-            Num2Words::new(2001).lang(Lang::English).year().to_words(),
-            Ok(String::from("two thousand and one"))
+            Num2Words::new(21)
+                .lang(Lang::English)
+                .fraction(8)
+                .to_words(),
+            Ok(String::from("two and five eighths"))
         );
         assert_eq!(
-            Num2Words::new(1901).lang(Lang::English).year().to_words(),
-            Ok(String::from("nineteen oh-one"))
+            Num2Words::new(5).lang(Lang::English).fraction(2).to_words(),
+            Ok(String::from("two and a half"))
         );
         assert_eq!(
-            Num2Words::new(5500).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty-five hundred"))
+            Num2Words::new(3)
+                .lang(Lang::English)
+                .fraction(100)
+                .to_words(),
+            Ok(String::from("three hundredths"))
         );
         assert_eq!(
-            Num2Words::new(500).lang(Lang::English).year().to_words(),
-            Ok(String::from("five hundred"))
+            Num2Words::new(-2)
+                .lang(Lang::English)
+                .fraction(3)
+                .to_words(),
+            Ok(String::from("minus two thirds"))
         );
         assert_eq!(
-            Num2Words::new(50).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty"))
+            Num2Words::new(0.75)
+                .lang(Lang::English)
+                .fraction(1)
+                .to_words(),
+            Ok(String::from("three quarters"))
         );
         assert_eq!(
-            Num2Words::new(0).lang(Lang::English).year().to_words(),
-            Ok(String::from("zero"))
+            Num2Words::new(8).lang(Lang::English).fraction(4).to_words(),
+            Ok(String::from("two"))
         );
         assert_eq!(
-            Num2Words::new(-44).lang(Lang::English).year().to_words(),
-            Ok(String::from("forty-four BC"))
+            Num2Words::new(3).lang(Lang::English).fraction(0).to_words(),
+            Err(num2words::Num2Err::ZeroDenominator)
         );
         assert_eq!(
-            Num2Words::new(1.1).lang(Lang::English).year().to_words(),
-            Err(num2words::Num2Err::FloatingYear)
+            Num2Words::new(f64::NAN)
+                .lang(Lang::English)
+                .fraction(2)
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::English)
+                .fraction(f64::NAN)
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
         );
     }
 
     #[test]
-    fn test_prefer() {
+    fn test_scientific() {
+        let scientific = |num: &str| {
+            Num2Words::parse(num)
+                .unwrap()
+                .lang(Lang::English)
+                .scientific()
+                .to_words()
+        };
         assert_eq!(
-            Num2Words::new(0)
+            scientific("6.022e23"),
+            Ok(String::from(
+                "six point zero two two times ten to the twenty-third"
+            ))
+        );
+        assert_eq!(
+            scientific("1.6E-19"),
+            Ok(String::from(
+                "one point six times ten to the minus nineteenth"
+            ))
+        );
+        assert_eq!(
+            scientific("-42"),
+            Ok(String::from("minus four point two times ten to the first"))
+        );
+        assert_eq!(
+            Num2Words::parse("6.022e23")
+                .unwrap()
                 .lang(Lang::English)
-                .prefer("oh")
+                .engineering()
                 .to_words(),
-            Ok(String::from("oh"))
+            Ok(String::from(
+                "six hundred two point two times ten to the twenty-first"
+            ))
         );
+        assert_eq!(scientific("0"), Ok(String::from("zero")));
         assert_eq!(
             Num2Words::new(0)
                 .lang(Lang::English)
-                .prefer("nil")
+                .engineering()
                 .to_words(),
-            Ok(String::from("nil"))
+            Ok(String::from("zero"))
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        let long = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .prefer("long")
+                .to_words()
+        };
+        assert_eq!(long(1e9), Ok(String::from("one thousand million")));
+        assert_eq!(
+            long(5_001_000_000.0),
+            Ok(String::from("five thousand one million"))
         );
+        assert_eq!(long(2e12), Ok(String::from("two billion")));
+        assert_eq!(long(3e15), Ok(String::from("three thousand billion")));
+        assert_eq!(long(1e18), Ok(String::from("one trillion")));
         assert_eq!(
-            Num2Words::new(0.005)
+            Num2Words::new(1e9)
                 .lang(Lang::English)
-                .prefer("oh")
+                .prefer("long")
+                .ordinal()
                 .to_words(),
-            Ok(String::from("point oh oh five"))
+            Ok(String::from("one thousand millionth"))
         );
+        // beyond the short scale, but within the long one
         assert_eq!(
-            Num2Words::new(2.05)
+            Num2Words::new(1e100)
                 .lang(Lang::English)
-                .prefer("nil")
+                .prefer("long")
                 .to_words(),
-            Ok(String::from("two point zero five"))
+            Ok(String::from("ten thousand sexdecillion"))
         );
     }
 
     #[test]
-    fn test_big_num() {
-        use crate::lang::en::MEGAS;
-        use num_bigfloat::BigFloat;
-
-        let mut num = BigFloat::from(1);
+    fn test_minor_units() {
+        let currency = |num: f64, currency: Currency| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .currency(currency)
+                .to_words()
+        };
+        assert_eq!(
+            currency(1.234, Currency::KWD),
+            Ok(String::from(
+                "one kuwaiti dinar and two hundred thirty-four fils"
+            ))
+        );
+        assert_eq!(
+            currency(0.005, Currency::KWD),
+            Ok(String::from("five fils"))
+        );
+        assert_eq!(
+            currency(100.5, Currency::JPY),
+            Ok(String::from("one hundred one yen"))
+        );
+        assert_eq!(currency(0.4, Currency::KRW), Ok(String::from("zero won")));
+        assert_eq!(
+            currency(42.999, Currency::DOLLAR),
+            Ok(String::from("forty-three dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(1.234).cheque(Currency::KWD).to_words(),
+            Ok(String::from("one and 234/1000 kuwaiti dinars"))
+        );
+        assert_eq!(
+            Num2Words::new(1500.4)
+                .cheque(Currency::JPY)
+                .prefer("fraction")
+                .to_words(),
+            Ok(String::from("one thousand five hundred yen"))
+        );
+    }
+
+    #[test]
+    fn test_currency_rounding() {
+        // amounts are rounded half away from zero to the minor unit, where
+        // they used to be truncated to the cent
+        let dollars = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .to_words()
+        };
+        assert_eq!(dollars(1.999), Ok(String::from("two dollars")));
+        assert_eq!(
+            dollars(1.994),
+            Ok(String::from("one dollar and ninety-nine cents"))
+        );
+        assert_eq!(
+            dollars(2.675),
+            Ok(String::from("two dollars and sixty-eight cents"))
+        );
+        assert_eq!(dollars(0.005), Ok(String::from("one cent")));
+        assert_eq!(dollars(-1.999), Ok(String::from("minus two dollars")));
+        assert_eq!(
+            Num2Words::new(1.999)
+                .cheque(Currency::DOLLAR)
+                .prefer("numeric")
+                .to_words(),
+            Ok(String::from("two dollars and 00 cents"))
+        );
+    }
+
+    #[test]
+    fn test_crypto() {
+        let currency = |num: f64, currency: Currency, prefs: &[&str]| {
+            prefs
+                .iter()
+                .fold(Num2Words::new(num).currency(currency), |n, p| n.prefer(*p))
+                .to_words()
+        };
+        assert_eq!(
+            currency(1.5, Currency::BTC, &[]),
+            Ok(String::from("one bitcoin and fifty million satoshis"))
+        );
+        assert_eq!(
+            currency(1.5, Currency::BTC, &["minor"]),
+            Ok(String::from("one hundred fifty million satoshis"))
+        );
+        assert_eq!(
+            currency(1.5, Currency::BTC, &["decimal"]),
+            Ok(String::from("one point five bitcoin"))
+        );
+        assert_eq!(
+            currency(0.000000021, Currency::ETH, &["gwei"]),
+            Ok(String::from("twenty-one gwei"))
+        );
+        assert_eq!(
+            currency(0.000000000000000042, Currency::ETH, &[]),
+            Ok(String::from("forty-two wei"))
+        );
+        assert_eq!(
+            currency(1.5, Currency::USD, &["minor"]),
+            Ok(String::from("one hundred fifty cents"))
+        );
+        assert_eq!(
+            currency(0.5, Currency::USD, &["decimal"]),
+            Ok(String::from("zero point five US dollars"))
+        );
+        assert_eq!(
+            currency(100.5, Currency::JPY, &["minor"]),
+            Ok(String::from("one hundred one yen"))
+        );
+        // gwei only divide ether
+        assert_eq!(
+            currency(2.0, Currency::USD, &["gwei"]),
+            Ok(String::from("two US dollars"))
+        );
+    }
+
+    #[test]
+    fn test_compact_currency() {
+        let compact = |num: f64, currency: Currency| {
+            Num2Words::new(num).compact_currency(currency, 3).to_words()
+        };
+        assert_eq!(
+            compact(2_500_000.0, Currency::DOLLAR),
+            Ok(String::from("two point five million dollars"))
+        );
+        assert_eq!(
+            compact(3e9, Currency::EUR),
+            Ok(String::from("three billion euros"))
+        );
+        assert_eq!(
+            compact(1_234_567.0, Currency::GBP),
+            Ok(String::from("about one point two three million pounds"))
+        );
+        assert_eq!(compact(1.0, Currency::EUR), Ok(String::from("one euro")));
+        assert_eq!(compact(0.0, Currency::EUR), Ok(String::from("zero euros")));
+        assert_eq!(
+            compact(1.234, Currency::DOLLAR),
+            Ok(String::from("one dollar and twenty-three cents"))
+        );
+        assert_eq!(
+            compact(0.5, Currency::DOLLAR),
+            Ok(String::from("fifty cents"))
+        );
+        assert_eq!(
+            compact(999.99, Currency::DOLLAR),
+            Ok(String::from("about one thousand dollars"))
+        );
+    }
+
+    #[test]
+    fn test_regions() {
+        let cardinal = |num: i64, lang: Lang| Num2Words::new(num).lang(lang).to_words();
+        assert_eq!(
+            cardinal(1234, Lang::English_US),
+            Ok(String::from("one thousand two hundred thirty-four"))
+        );
+        assert_eq!(
+            cardinal(101, Lang::English_CA),
+            Ok(String::from("one hundred one"))
+        );
+        assert_eq!(
+            cardinal(101, Lang::English_GB),
+            Ok(String::from("one hundred and one"))
+        );
+        assert_eq!(
+            cardinal(100_234, Lang::English_AU),
+            Ok(String::from(
+                "one hundred thousand two hundred and thirty-four"
+            ))
+        );
+        assert_eq!(
+            cardinal(1_000_005, Lang::English_IN),
+            Ok(String::from("one million and five"))
+        );
+        assert_eq!(
+            cardinal(1100, Lang::English_GB),
+            Ok(String::from("one thousand one hundred"))
+        );
+        assert_eq!(
+            cardinal(101_000, Lang::English_GB),
+            Ok(String::from("one hundred and one thousand"))
+        );
+        assert_eq!(
+            cardinal(120_000, Lang::English_GB),
+            Ok(String::from("one hundred and twenty thousand"))
+        );
+        assert_eq!(
+            cardinal(120_101, Lang::English_GB),
+            Ok(String::from(
+                "one hundred and twenty thousand one hundred and one"
+            ))
+        );
+
+        let currency = |num: f64, lang: Lang, currency: Currency| {
+            Num2Words::new(num).lang(lang).currency(currency).to_words()
+        };
+        assert_eq!(
+            currency(2.0, Lang::English_US, Currency::USD),
+            Ok(String::from("two dollars"))
+        );
+        assert_eq!(
+            currency(2.0, Lang::English_GB, Currency::USD),
+            Ok(String::from("two US dollars"))
+        );
+        assert_eq!(
+            currency(2.0, Lang::English_US, Currency::GBP),
+            Ok(String::from("two pounds sterling"))
+        );
+        assert_eq!(
+            currency(1.01, Lang::English_GB, Currency::GBP),
+            Ok(String::from("one pound and one penny"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::English_GB)
+                .currency(Currency::GBP)
+                .prefer("p")
+                .to_words(),
+            Ok(String::from("fifty p"))
+        );
+        assert_eq!(
+            currency(1.01, Lang::English_IN, Currency::INR),
+            Ok(String::from("one rupee and one paisa"))
+        );
+        assert_eq!(
+            currency(5.0, Lang::English_AU, Currency::AUD),
+            Ok(String::from("five dollars"))
+        );
+        assert_eq!(
+            currency(5.0, Lang::English_CA, Currency::CAD),
+            Ok(String::from("five dollars"))
+        );
+        // the language without a region keeps the names of the currencies
+        assert_eq!(
+            currency(5.0, Lang::English, Currency::AUD),
+            Ok(String::from("five australian dollars"))
+        );
+    }
+
+    #[test]
+    fn test_currency_parts() {
+        let currency = |num: f64, prefs: &[&str]| {
+            prefs
+                .iter()
+                .fold(Num2Words::new(num).currency(Currency::DOLLAR), |n, p| {
+                    n.prefer(*p)
+                })
+                .to_words()
+        };
+        assert_eq!(
+            currency(0.5, &["both"]),
+            Ok(String::from("zero dollars and fifty cents"))
+        );
+        assert_eq!(
+            currency(1.0, &["both"]),
+            Ok(String::from("one dollar and zero cents"))
+        );
+        assert_eq!(
+            currency(1.5, &["conjunction:with"]),
+            Ok(String::from("one dollar with fifty cents"))
+        );
+        assert_eq!(
+            currency(1.5, &["conjunction:none"]),
+            Ok(String::from("one dollar fifty cents"))
+        );
+        // only the words of the language are accepted
+        assert_eq!(
+            currency(1.5, &["conjunction:et"]),
+            Ok(String::from("one dollar and fifty cents"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .currency(Currency::JPY)
+                .prefer("both")
+                .to_words(),
+            Ok(String::from("one yen"))
+        );
+    }
+
+    #[test]
+    fn test_cheque() {
+        let cheque = |num: f64, prefs: &[&str]| {
+            prefs
+                .iter()
+                .fold(Num2Words::new(num).cheque(Currency::DOLLAR), |n, p| {
+                    n.prefer(*p)
+                })
+                .to_words()
+        };
+        assert_eq!(
+            cheque(42.01, &[]),
+            Ok(String::from("forty-two and 01/100 dollars"))
+        );
+        assert_eq!(
+            cheque(1.0, &["capitalized"]),
+            Ok(String::from("One and 00/100 dollars"))
+        );
+        assert_eq!(
+            cheque(0.5, &["numeric"]),
+            Ok(String::from("zero dollars and 50 cents"))
+        );
+        assert_eq!(
+            cheque(42.01, &["words", "only"]),
+            Ok(String::from("forty-two dollars and one cent only"))
+        );
+        assert_eq!(
+            cheque(42.01, &["numeric", "only", "uppercase"]),
+            Ok(String::from("FORTY-TWO DOLLARS AND 01 CENTS ONLY"))
+        );
+        assert_eq!(
+            cheque(1234.567, &[]),
+            Ok(String::from(
+                "one thousand two hundred and thirty-four and 57/100 dollars"
+            ))
+        );
+        assert_eq!(cheque(-1.0, &[]), Err(Num2Err::InvalidCheque));
+        assert_eq!(cheque(f64::INFINITY, &[]), Err(Num2Err::InvalidCheque));
+    }
+
+    #[test]
+    fn test_compact() {
+        let compact = |num: f64, significant: usize| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .compact(significant)
+                .to_words()
+        };
+        assert_eq!(
+            compact(2_500_000.0, 3),
+            Ok(String::from("two point five million"))
+        );
+        assert_eq!(
+            compact(2_512_000.0, 2),
+            Ok(String::from("about two point five million"))
+        );
+        assert_eq!(compact(999_999.0, 2), Ok(String::from("about one million")));
+        assert_eq!(compact(42_000.0, 3), Ok(String::from("forty-two thousand")));
+        assert_eq!(
+            compact(123.0, 3),
+            Ok(String::from("one hundred twenty-three"))
+        );
+        assert_eq!(
+            compact(-1.5e12, 3),
+            Ok(String::from("minus one point five trillion"))
+        );
+        assert_eq!(
+            Num2Words::new(1_500_000)
+                .lang(Lang::English)
+                .prefer("half")
+                .compact(2)
+                .to_words(),
+            Ok(String::from("one and a half million"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::English)
+                .percent()
+                .to_words(),
+            Ok(String::from("twelve point five percent"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::English).percent().to_words(),
+            Ok(String::from("one percent"))
+        );
+        assert_eq!(
+            Num2Words::new(-3).lang(Lang::English).permille().to_words(),
+            Ok(String::from("minus three per mille"))
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        assert_eq!(
+            Num2Words::new(14)
+                .lang(Lang::English)
+                .time(5, None)
+                .to_words(),
+            Ok(String::from("two oh five pm"))
+        );
+        assert_eq!(
+            Num2Words::new(14)
+                .lang(Lang::English)
+                .prefer("24h")
+                .time(5, None)
+                .to_words(),
+            Ok(String::from("fourteen oh five"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .time(0, None)
+                .to_words(),
+            Ok(String::from("twelve am"))
+        );
+        assert_eq!(
+            Num2Words::new(12)
+                .lang(Lang::English)
+                .time(45, None)
+                .to_words(),
+            Ok(String::from("twelve forty-five pm"))
+        );
+        assert_eq!(
+            Num2Words::new(23)
+                .lang(Lang::English)
+                .time(59, Some(59))
+                .to_words(),
+            Ok(String::from("eleven fifty-nine pm and fifty-nine seconds"))
+        );
+        assert_eq!(
+            Num2Words::new(7)
+                .lang(Lang::English)
+                .prefer("24h")
+                .time(0, Some(1))
+                .to_words(),
+            Ok(String::from("oh seven hundred and one second"))
+        );
+        let clock = |hours: i64, minutes: u8, preference: &str| {
+            Num2Words::new(hours)
+                .lang(Lang::English)
+                .prefer("24h")
+                .prefer(preference)
+                .time(minutes, None)
+                .to_words()
+        };
+        assert_eq!(clock(0, 0, ""), Ok(String::from("zero hundred hours")));
+        assert_eq!(clock(0, 0, "oh"), Ok(String::from("oh hundred hours")));
+        assert_eq!(clock(0, 30, ""), Ok(String::from("zero zero thirty")));
+        assert_eq!(clock(9, 5, ""), Ok(String::from("oh nine oh five")));
+        assert_eq!(clock(9, 30, ""), Ok(String::from("oh nine thirty")));
+        assert_eq!(clock(10, 0, ""), Ok(String::from("ten hundred")));
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(
+            Num2Words::new(1901)
+                .lang(Lang::English)
+                .date(3, 3)
+                .to_words(),
+            Ok(String::from("March third, nineteen oh-one"))
+        );
+        assert_eq!(
+            Num2Words::new(2024)
+                .lang(Lang::English)
+                .date(12, 31)
+                .to_words(),
+            Ok(String::from("December thirty-first, twenty twenty-four"))
+        );
+        assert_eq!(
+            Num2Words::new(1901)
+                .lang(Lang::English)
+                .prefer("dmy")
+                .date(3, 3)
+                .to_words(),
+            Ok(String::from("the third of March, nineteen oh-one"))
+        );
+        assert_eq!(
+            Num2Words::new(-44)
+                .lang(Lang::English)
+                .date(3, 15)
+                .to_words(),
+            Ok(String::from("March fifteenth, forty-four BC"))
+        );
+        assert_eq!(
+            Num2Words::new(1e30)
+                .lang(Lang::English)
+                .date(1, 1)
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+        let date = |lang: Lang, preference: &str| {
+            Num2Words::new(2024)
+                .lang(lang)
+                .prefer(preference)
+                .date(3, 3)
+                .to_words()
+        };
+        for lang in [Lang::English_GB, Lang::English_IN, Lang::English_AU] {
+            assert_eq!(
+                date(lang, ""),
+                Ok(String::from("the third of March, twenty twenty-four"))
+            );
+            assert_eq!(
+                date(lang, "mdy"),
+                Ok(String::from("March third, twenty twenty-four"))
+            );
+        }
+        for lang in [Lang::English_US, Lang::English_CA] {
+            assert_eq!(
+                date(lang, ""),
+                Ok(String::from("March third, twenty twenty-four"))
+            );
+        }
+        assert_eq!(
+            date(Lang::English_US, "dmy"),
+            Ok(String::from("the third of March, twenty twenty-four"))
+        );
+    }
+
+    #[test]
+    fn test_digits() {
+        let digits = |s: &str| Num2Words::parse_digits(s).unwrap().lang(Lang::English);
+        assert_eq!(
+            digits("555-0142").prefer("oh").to_words(),
+            Ok(String::from("five five five oh one four two"))
+        );
+        assert_eq!(
+            digits("555-0142").to_words(),
+            Ok(String::from("five five five zero one four two"))
+        );
+        assert_eq!(
+            digits("5555").prefer("pairs").to_words(),
+            Ok(String::from("fifty-five fifty-five"))
+        );
+        assert_eq!(
+            digits("(020) 7946 0018")
+                .prefer("pairs")
+                .prefer("oh")
+                .to_words(),
+            Ok(String::from(
+                "oh twenty seventy-nine forty-six oh oh eighteen"
+            ))
+        );
+        assert_eq!(
+            digits("123456").prefer("triples").to_words(),
+            Ok(String::from(
+                "one hundred twenty-three four hundred fifty-six"
+            ))
+        );
+        assert_eq!(
+            digits("555-0142").prefer("double").prefer("oh").to_words(),
+            Ok(String::from("triple five oh one four two"))
+        );
+        assert_eq!(
+            digits("1000 0000")
+                .prefer("double")
+                .prefer("nil")
+                .to_words(),
+            Ok(String::from("one triple nil double nil double nil"))
+        );
+    }
+
+    #[test]
+    fn test_regnal() {
+        assert_eq!(
+            Num2Words::new(8)
+                .lang(Lang::English)
+                .regnal("Henry")
+                .to_words(),
+            Ok(String::from("Henry the eighth"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::English)
+                .regnal("Louis")
+                .to_words(),
+            Ok(String::from("Louis the twenty-first"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::English)
+                .prefer("capitalized")
+                .regnal("Louis")
+                .to_words(),
+            Ok(String::from("Louis the Twenty-first"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang(Lang::English)
+                .regnal("Louis")
+                .to_words(),
+            Err(num2words::Num2Err::FloatingOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::English)
+                .unit(Unit::Kilometre)
+                .to_words(),
+            Ok(String::from("forty-two point five kilometres"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::English)
+                .unit(Unit::Foot)
+                .to_words(),
+            Ok(String::from("one foot"))
+        );
+        assert_eq!(
+            Num2Words::new(-1)
+                .lang(Lang::English)
+                .unit(Unit::Celsius)
+                .to_words(),
+            Ok(String::from("minus one degree Celsius"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .unit(Unit::Foot)
+                .to_words(),
+            Ok(String::from("zero feet"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::English).year().to_words(),
+            Ok(String::from("nineteen ninety"))
+        );
+        assert_eq!(
+            Num2Words::new(5555).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty-five fifty-five"))
+        );
+        assert_eq!(
+            Num2Words::new(2022).lang(Lang::English).year().to_words(),
+            Ok(String::from("twenty twenty-two"))
+        );
+        assert_eq!(
+            Num2Words::new(2001).lang(Lang::English).year().to_words(),
+            Ok(String::from("two thousand and one"))
+        );
+        assert_eq!(
+            Num2Words::new(1901).lang(Lang::English).year().to_words(),
+            Ok(String::from("nineteen oh-one"))
+        );
+        assert_eq!(
+            Num2Words::new(5500).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty-five hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(500).lang(Lang::English).year().to_words(),
+            Ok(String::from("five hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(50).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty"))
+        );
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::English).year().to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::English).year().to_words(),
+            Ok(String::from("forty-four BC"))
+        );
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::English).year().to_words(),
+            Err(num2words::Num2Err::FloatingYear)
+        );
+        assert_eq!(
+            Num2Words::new(1e30).lang(Lang::English).year().to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_prefer() {
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("oh")
+                .to_words(),
+            Ok(String::from("oh"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("nil")
+                .to_words(),
+            Ok(String::from("nil"))
+        );
+        assert_eq!(
+            Num2Words::new(0.005)
+                .lang(Lang::English)
+                .prefer("oh")
+                .to_words(),
+            Ok(String::from("point oh oh five"))
+        );
+        assert_eq!(
+            Num2Words::new(2.05)
+                .lang(Lang::English)
+                .prefer("nil")
+                .to_words(),
+            Ok(String::from("two point zero five"))
+        );
+    }
+
+    #[test]
+    fn test_big_num() {
+        use crate::lang::en::MEGAS;
+        use num_bigfloat::BigFloat;
+
+        let mut num = BigFloat::from(1);
         for m in MEGAS {
             num *= BigFloat::from(1000);
             assert_eq!(
@@ -631,7 +1912,24 @@ mod tests {
                 .lang(Lang::English)
                 .cardinal()
                 .to_words(),
-            Err(num2words::Num2Err::CannotConvert)
+            Ok(String::from("ten duotrigintillion"))
+        );
+
+        assert_eq!(
+            Num2Words::parse("2.5e70")
+                .unwrap()
+                .lang(Lang::English)
+                .to_words(),
+            Ok(String::from("twenty-five duovigintillion"))
+        );
+
+        assert_eq!(
+            Num2Words::parse("7e165")
+                .unwrap()
+                .lang(Lang::English)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("seven quattuorquinquagintillionth"))
         );
     }
 
@@ -4,8 +4,14 @@ use std::str::FromStr;
 
 use num_bigfloat::BigFloat;
 
-use super::Language;
-use crate::{Currency, Num2Err};
+use super::illion::illion_stem;
+use super::{
+    digit_chunks, join_parts, minor_digits, minor_fraction, read_digit_chunk, split_minor,
+    ChequeCents, Language, Scale,
+};
+use crate::currency::inflect;
+use crate::noun::{Gender, Noun};
+use crate::{Currency, Lang, Num2Err, Unit};
 // Reference that can hopefully be implemented seamlessly: https://es.wikipedia.org/wiki/Anexo:Nombres_de_los_n%C3%BAmeros_en_espa%C3%B1ol
 const UNIDADES: [&str; 10] = [
     "", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
@@ -179,6 +185,21 @@ pub mod ordinal {
         "vigintillonésim",
     ];
 }
+const MESES: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
 #[derive(Clone, Default, Debug, PartialEq, Eq)]
 pub struct Spanish {
     /// Negative flavour like "bajo cero", "menos", "negativo"
@@ -190,6 +211,51 @@ pub struct Spanish {
     feminine: bool,
     // Plural for ordinal numbers
     plural: bool,
+    // Reads times on a 24-hour clock, e.g. "las catorce y cinco"
+    prefer_24h: bool,
+    // Reads digit sequences by chunks of this size, e.g. "cincuenta y cinco" for pairs
+    digit_group: usize,
+    // Reads compact numbers ending in a half as "dos millones y medio"
+    prefer_half: bool,
+    // Names every power of a thousand, e.g. "un billón" for 10^9 instead of "mil millones"
+    short_scale: bool,
+    // Reads both parts of an amount of money, e.g. "cero dólares con cincuenta centavos"
+    both_parts: bool,
+    // Joins the parts of an amount of money instead of "con", empty for none
+    conjunction: Option<&'static str>,
+    // Country whose currency goes without its nationality, e.g. "pesos" in Mexico
+    region: Option<RegionSpanish>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegionSpanish {
+    ES,
+    MX,
+    AR,
+    CO,
+    CL,
+}
+
+impl RegionSpanish {
+    /// Word for the decimal separator written in the country, e.g. "coma" in
+    /// Spain for "3,5"
+    pub fn decimal_char(self) -> DecimalChar {
+        match self {
+            RegionSpanish::MX => DecimalChar::Punto,
+            _ => DecimalChar::Coma,
+        }
+    }
+
+    // Peso of the country, if any
+    fn peso(self) -> Option<Currency> {
+        match self {
+            RegionSpanish::ES => None,
+            RegionSpanish::MX => Some(Currency::MXN),
+            RegionSpanish::AR => Some(Currency::ARS),
+            RegionSpanish::CO => Some(Currency::COP),
+            RegionSpanish::CL => Some(Currency::CLP),
+        }
+    }
 }
 #[allow(unused)]
 impl Spanish {
@@ -198,6 +264,7 @@ impl Spanish {
         Self {
             decimal_char,
             feminine,
+            digit_group: 1,
             ..Default::default()
         }
     }
@@ -266,6 +333,94 @@ impl Spanish {
         }
     }
 
+    #[inline(always)]
+    pub fn set_24h(&mut self, prefer_24h: bool) -> &mut Self {
+        self.prefer_24h = prefer_24h;
+        self
+    }
+
+    #[inline(always)]
+    pub fn with_24h(self, prefer_24h: bool) -> Self {
+        Self { prefer_24h, ..self }
+    }
+
+    #[inline(always)]
+    pub fn set_digit_group(&mut self, digit_group: usize) -> &mut Self {
+        self.digit_group = digit_group;
+        self
+    }
+
+    #[inline(always)]
+    pub fn with_digit_group(self, digit_group: usize) -> Self {
+        Self {
+            digit_group,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    pub fn set_half(&mut self, prefer_half: bool) -> &mut Self {
+        self.prefer_half = prefer_half;
+        self
+    }
+
+    #[inline(always)]
+    pub fn with_half(self, prefer_half: bool) -> Self {
+        Self {
+            prefer_half,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    pub fn set_scale(&mut self, scale: Scale) -> &mut Self {
+        self.short_scale = scale == Scale::Short;
+        self
+    }
+
+    #[inline(always)]
+    pub fn with_scale(self, scale: Scale) -> Self {
+        Self {
+            short_scale: scale == Scale::Short,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_currency_parts(self, both_parts: bool, conjunction: Option<&'static str>) -> Self {
+        Self {
+            both_parts,
+            conjunction,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_region(self, region: Option<RegionSpanish>) -> Self {
+        Self { region, ..self }
+    }
+
+    // Index in `MILLAR` of the given triplet, odd triplets being thousands on the long scale
+    #[inline(always)]
+    fn milliard_index(&self, i: usize) -> usize {
+        match (self.short_scale, i % 2) {
+            (true, _) => i,
+            (false, 0) => i / 2 + 1,
+            _ => 1,
+        }
+    }
+
+    // Name of the milliard at the given index, systematic beyond "vigintillón", e.g.
+    // "unvigintillones"
+    fn milliard(&self, index: usize, plural: bool) -> String {
+        match (MILLAR.get(index), MILLARES.get(index), plural) {
+            (Some(millar), _, false) => String::from(*millar),
+            (_, Some(millares), true) => String::from(*millares),
+            (_, _, false) => format!("{}illón", illion_stem(index - 1)),
+            (_, _, true) => format!("{}illones", illion_stem(index - 1)),
+        }
+    }
+
     // Converts Integer BigFloat to a vector of u64
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
         let mut thousands = Vec::new();
@@ -281,200 +436,240 @@ impl Spanish {
     }
 
     fn currencies(&self, currency: Currency, plural_form: bool) -> String {
-        match currency {
+        if let Some((unit, _)) = currency.custom_nouns(Lang::Spanish) {
+            return unit.form(plural_form).to_string();
+        }
+        // the local peso goes without its nationality, e.g. "pesos" in Mexico but
+        // "pesos mexicanos" elsewhere
+        if self.region.and_then(RegionSpanish::peso) == Some(currency) {
+            return inflect("peso{}", plural_form);
+        }
+        let name = match currency {
             Currency::AED => "dirham{}",
+            Currency::AFN => "afgani{}",
+            Currency::ALL => "lek{}",
+            Currency::AMD => "dram{}",
+            Currency::AOA => "kuanza{}",
             Currency::ARS => "peso{} argentino{}",
-            Currency::AUD => {
-                if plural_form {
-                    "dólares australianos"
-                } else {
-                    "dólar australiano"
-                }
-            }
-            Currency::BRL => {
-                if plural_form {
-                    "reales brasileño"
-                } else {
-                    "real brasileño"
-                }
-            }
-            Currency::C => "cordoba{}",
-            Currency::CAD => {
-                if plural_form {
-                    "dólares canadienses"
-                } else {
-                    "dólar canadiense"
-                }
-            }
+            Currency::AUD => "dólar australiano|dólares australianos",
+            Currency::AWG => "florín arubeño|florines arubeños",
+            Currency::AZN => "manat azerí|manats azeríes",
+            Currency::B | Currency::PAB => "balboa{}",
+            Currency::BAM => "marco convertible|marcos convertibles",
+            Currency::BBD => "dólar barbadense|dólares barbadenses",
+            Currency::BDT => "taka{}",
+            Currency::BGN => "lev búlgaro|leva búlgaros",
+            Currency::BHD => "dinar bareiní|dinares bareiníes",
+            Currency::BIF => "franco burundés|francos burundeses",
+            Currency::BMD => "dólar bermudeño|dólares bermudeños",
+            Currency::BND => "dólar de Brunéi|dólares de Brunéi",
+            Currency::BOB => "boliviano{}",
+            Currency::BOV => "mvdol boliviano|mvdols bolivianos",
+            Currency::BRL => "real brasileño|reales brasileños",
+            Currency::BSD => "dólar bahameño|dólares bahameños",
+            Currency::BTC => "bitcoin{}",
+            Currency::BTN => "ngultrum{}",
+            Currency::BWP => "pula{}",
+            Currency::BYN => "rublo{} bielorruso{}",
+            Currency::BZD => "dólar beliceño|dólares beliceños",
+            Currency::C | Currency::NIO => "cordoba{}",
+            Currency::CAD => "dólar canadiense|dólares canadienses",
+            Currency::CDF => "franco congoleño|francos congoleños",
+            Currency::CHE => "euro WIR|euros WIR",
             Currency::CHF => "franco{}",
+            Currency::CHW => "franco WIR|francos WIR",
+            Currency::CLF => "unidad de fomento|unidades de fomento",
             Currency::CLP => "peso{} chileno{}",
-            Currency::CNY => {
-                if plural_form {
-                    "yuanes"
-                } else {
-                    "yuan"
-                }
-            }
+            Currency::CNY => "yuan|yuanes",
             Currency::COP => "peso{} colombiano{}",
-            Currency::CRC => {
-                if plural_form {
-                    "colones"
-                } else {
-                    "colón"
-                }
-            }
-            Currency::DINAR => {
-                if plural_form {
-                    "dinares"
-                } else {
-                    "dinar"
-                }
-            }
-            Currency::DOLLAR => {
-                if plural_form {
-                    "dólares"
-                } else {
-                    "dólar"
-                }
-            }
-            Currency::DZD => {
-                if plural_form {
-                    "dinares argelinos"
-                } else {
-                    "dinar argelino"
-                }
-            }
+            Currency::COU => "unidad de valor real|unidades de valor real",
+            Currency::CRC => "colón|colones",
+            Currency::CUP => "peso{} cubano{}",
+            Currency::CVE => "escudo{} caboverdiano{}",
+            Currency::CZK => "corona{} checa{}",
+            Currency::DINAR => "dinar|dinares",
+            Currency::DJF => "franco yibutiano|francos yibutianos",
+            Currency::DKK => "corona{} danesa{}",
+            Currency::DOLLAR => "dólar|dólares",
+            Currency::DOP => "peso{} dominicano{}",
+            Currency::DZD => "dinar argelino|dinares argelinos",
+            Currency::EGP => "libra{} egipcia{}",
+            Currency::ERN => "nakfa{}",
+            Currency::ETB => "bir{} etíope{}",
+            Currency::ETH => "ether{}",
             Currency::EUR => "euro{}",
+            Currency::FJD => "dólar fiyiano|dólares fiyianos",
+            Currency::FKP => "libra{} malvinense{}",
             Currency::GBP => "libra{}",
-            Currency::HKD => {
-                if plural_form {
-                    "dólares de Hong Kong"
-                } else {
-                    "dólar de Hong Kong"
-                }
-            }
+            Currency::GEL => "lari{}",
+            Currency::GHS => "cedi{}",
+            Currency::GIP => "libra{} gibraltareña{}",
+            Currency::GMD => "dalasi{}",
+            Currency::GNF => "franco guineano|francos guineanos",
+            Currency::GTQ | Currency::Q => "quetzal|quetzales",
+            Currency::GYD => "dólar guyanés|dólares guyaneses",
+            Currency::HKD => "dólar de Hong Kong|dólares de Hong Kong",
+            Currency::HNL => "lempira{}",
+            Currency::HTG => "gourde{}",
+            Currency::HUF => "forinto{}",
             Currency::IDR => "rupia{} indonesia{}",
-            Currency::ILS => {
-                // https://www.rae.es/dpd/s%C3%A9quel
-                if plural_form {
-                    "séqueles"
-                } else {
-                    "séquel"
-                }
-            }
+            // https://www.rae.es/dpd/s%C3%A9quel
+            Currency::ILS => "séquel|séqueles",
             Currency::INR => "rupia{}",
-            Currency::JPY => {
-                if plural_form {
-                    "yenes"
-                } else {
-                    "yen"
-                }
-            }
+            Currency::IQD => "dinar iraquí|dinares iraquíes",
+            Currency::IRR => "rial iraní|riales iraníes",
+            Currency::ISK => "corona{} islandesa{}",
+            Currency::JMD => "dólar jamaicano|dólares jamaicanos",
+            Currency::JOD => "dinar jordano|dinares jordanos",
+            Currency::JPY => "yen|yenes",
+            Currency::KES => "chelín keniano|chelines kenianos",
+            Currency::KGS => "som{}",
+            Currency::KHR => "riel{}",
+            Currency::KMF => "franco comorense|francos comorenses",
+            Currency::KPW => "won{} norcoreano{}",
             Currency::KRW => "won{}",
-            Currency::KWD => {
-                if plural_form {
-                    "dinares kuwaitíes"
-                } else {
-                    "dinar kuwaití"
-                }
-            }
+            Currency::KWD => "dinar kuwaití|dinares kuwaitíes",
+            Currency::KYD => "dólar de las Islas Caimán|dólares de las Islas Caimán",
             Currency::KZT => "tenge{}",
+            Currency::LAK => "kip{}",
+            Currency::LBP => "libra{} libanesa{}",
+            Currency::LKR => "rupia{} esrilanquesa{}",
+            Currency::LRD => "dólar liberiano|dólares liberianos",
+            Currency::LSL => "loti|malotis",
+            Currency::LYD => "dinar libio|dinares libios",
+            Currency::MAD => "dírham marroquí|dírhams marroquíes",
+            Currency::MDL => "leu moldavo|lei moldavos",
+            Currency::MGA => "ariary",
+            Currency::MKD => "denar macedonio|denares macedonios",
+            Currency::MMK => "kyat{}",
+            Currency::MNT => "tugrik{}",
+            Currency::MOP => "pataca{}",
+            Currency::MRU => "uguiya{}",
+            Currency::MUR => "rupia{} mauriciana{}",
+            Currency::MVR => "rufiyaa",
+            Currency::MWK => "kwacha malauí|kwachas malauíes",
             Currency::MXN => "peso{} mexicano{}",
+            Currency::MXV => "unidad de inversión mexicana|unidades de inversión mexicanas",
             Currency::MYR => "ringgit{}",
+            Currency::MZN => "metical mozambiqueño|meticales mozambiqueños",
+            Currency::NAD => "dólar namibio|dólares namibios",
+            Currency::NGN => "naira{}",
             Currency::NOK => "corona{} noruega{}",
-            Currency::NZD => {
-                if plural_form {
-                    "dólares neozelandeses"
-                } else {
-                    "dólar neozelandés"
-                }
-            }
-            Currency::PEN => {
-                if plural_form {
-                    "soles"
-                } else {
-                    "sol"
-                }
-            }
-            Currency::PESO => "peso{}",
-            Currency::PHP => "peso{}",
+            Currency::NPR => "rupia nepalí|rupias nepalíes",
+            Currency::NZD => "dólar neozelandés|dólares neozelandeses",
+            Currency::OMR => "rial omaní|riales omaníes",
+            Currency::PEN => "sol|soles",
+            Currency::PESO | Currency::PHP => "peso{}",
+            Currency::PGK => "kina{}",
+            Currency::PKR => "rupia pakistaní|rupias pakistaníes",
             Currency::PLN => "zloty{}",
-            Currency::QAR => {
-                if plural_form {
-                    "riyales cataríes"
-                } else {
-                    "riyal catarí"
-                }
-            }
-            Currency::RIYAL => {
-                if plural_form {
-                    "riyales"
-                } else {
-                    "riyal"
-                }
-            }
+            Currency::PYG => "guaraní|guaraníes",
+            Currency::QAR => "riyal catarí|riyales cataríes",
+            Currency::RIYAL => "riyal|riyales",
+            Currency::RON => "leu rumano|lei rumanos",
+            Currency::RSD => "dinar serbio|dinares serbios",
             Currency::RUB => "rublo{} ruso{}",
-            Currency::SAR => {
-                if plural_form {
-                    "riyales saudíes"
-                } else {
-                    "riyal saudí"
-                }
-            }
-            Currency::SGD => {
-                if plural_form {
-                    "dólares singapurenses"
-                } else {
-                    "dólar singapurense"
-                }
-            }
-            Currency::THB => {
-                if plural_form {
-                    "bahts tailandeses"
-                } else {
-                    "baht tailandés"
-                }
-            }
+            Currency::RWF => "franco ruandés|francos ruandeses",
+            Currency::SAR => "riyal saudí|riyales saudíes",
+            Currency::SBD => "dólar de las Islas Salomón|dólares de las Islas Salomón",
+            Currency::SCR => "rupia{} seychellense{}",
+            Currency::SDG => "libra{} sudanesa{}",
+            Currency::SEK => "corona{} sueca{}",
+            Currency::SGD => "dólar singapurense|dólares singapurenses",
+            Currency::SHP => "libra{} de Santa Elena",
+            Currency::SLE => "leone{}",
+            Currency::SOS => "chelín somalí|chelines somalíes",
+            Currency::SRD => "dólar surinamés|dólares surinameses",
+            Currency::SSP => "libra{} sursudanesa{}",
+            Currency::STN => "dobra{}",
+            Currency::SVC => "colón salvadoreño|colones salvadoreños",
+            Currency::SYP => "libra{} siria{}",
+            Currency::SZL => "lilangeni|emalangeni",
+            Currency::THB => "baht tailandés|bahts tailandeses",
+            Currency::TJS => "somoni{}",
+            Currency::TMT => "manat turcomano|manats turcomanos",
+            Currency::TND => "dinar tunecino|dinares tunecinos",
+            Currency::TOP => "pa'anga",
             Currency::TRY => "lira{}",
-            Currency::TWD => {
-                if plural_form {
-                    "dólares taiwaneses"
-                } else {
-                    "dólar taiwanes"
-                }
-            }
+            Currency::TTD => "dólar trinitense|dólares trinitenses",
+            Currency::TWD => "dólar taiwanes|dólares taiwaneses",
+            Currency::TZS => "chelín tanzano|chelines tanzanos",
             Currency::UAH => "grivna{}",
-            Currency::USD => {
-                if plural_form {
-                    "dólares estadounidenses"
-                } else {
-                    "dólar estadounidense"
-                }
+            Currency::UGX => "chelín ugandés|chelines ugandeses",
+            Currency::USD => "dólar estadounidense|dólares estadounidenses",
+            Currency::USN => {
+                "dólar estadounidense (día siguiente)|dólares estadounidenses (día siguiente)"
             }
+            Currency::UYI => "unidad indexada uruguaya|unidades indexadas uruguayas",
             Currency::UYU => "peso{} uruguayo{}",
-            Currency::VES => {
-                if plural_form {
-                    "bolivares"
-                } else {
-                    "bolivar"
-                }
-            }
+            Currency::UYW => "unidad previsional|unidades previsionales",
+            Currency::UZS => "som uzbeko|soms uzbekos",
+            Currency::VED => "bolívar digital|bolívares digitales",
+            Currency::VES => "bolivar|bolivares",
             Currency::VND => "dong{}",
+            Currency::VUV => "vatu{}",
+            Currency::WST => "tala{}",
+            Currency::XAF | Currency::XOF => "franco CFA|francos CFA",
+            Currency::XAG => "onza troy de plata|onzas troy de plata",
+            Currency::XAU => "onza troy de oro|onzas troy de oro",
+            Currency::XBA => "unidad compuesta europea|unidades compuestas europeas",
+            Currency::XBB => "unidad monetaria europea|unidades monetarias europeas",
+            Currency::XBC => "unidad de cuenta europea 9|unidades de cuenta europeas 9",
+            Currency::XBD => "unidad de cuenta europea 17|unidades de cuenta europeas 17",
+            Currency::XCD => "dólar del Caribe Oriental|dólares del Caribe Oriental",
+            Currency::XCG => "florín caribeño|florines caribeños",
+            Currency::XDR => "derecho especial de giro|derechos especiales de giro",
+            Currency::XPD => "onza troy de paladio|onzas troy de paladio",
+            Currency::XPF => "franco CFP|francos CFP",
+            Currency::XPT => "onza troy de platino|onzas troy de platino",
+            Currency::XSU => "sucre{}",
+            Currency::XTS => "unidad de prueba|unidades de prueba",
+            Currency::XUA => "unidad de cuenta del BAD|unidades de cuenta del BAD",
+            Currency::XXX => "unidad|unidades",
+            Currency::YER => "rial yemení|riales yemeníes",
             Currency::ZAR => "rand{} sudafricano{}",
-            _ => return currency.default_string(plural_form),
-        }
-        .replace("{}", if plural_form { "s" } else { "" })
+            Currency::ZMW => "kwacha zambiano|kwachas zambianos",
+            Currency::ZWG => "oro de Zimbabue|oro de Zimbabue",
+            Currency::Custom(_) => return currency.default_string(plural_form),
+        };
+        inflect(name, plural_form)
     }
 
     fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        if let Some((_, subunit)) = currency.custom_nouns(Lang::Spanish) {
+            return subunit.form(plural_form).to_string();
+        }
         match currency {
             Currency::DOLLAR | Currency::PHP => "centavo{}",
-            Currency::GBP => "penique{}",
+            Currency::FKP | Currency::GBP | Currency::GIP | Currency::SHP => "penique{}",
             Currency::INR => "paisa{}",
             Currency::JPY => "sen",
             Currency::CHF | Currency::PEN | Currency::EUR => "céntimo{}",
-            Currency::UYU | Currency::B => "céntesimo{}",
-            Currency::CRC | Currency::VES => "céntimo{}",
+            Currency::UYU | Currency::B | Currency::PAB => "centésimo{}",
+            Currency::AOA
+            | Currency::CRC
+            | Currency::PYG
+            | Currency::STN
+            | Currency::VED
+            | Currency::VES => "céntimo{}",
+            // the centime of the francs and dirhams
+            Currency::BIF
+            | Currency::CDF
+            | Currency::CHW
+            | Currency::DJF
+            | Currency::DZD
+            | Currency::GNF
+            | Currency::HTG
+            | Currency::KMF
+            | Currency::MAD
+            | Currency::RWF
+            | Currency::XAF
+            | Currency::XOF
+            | Currency::XPF => "céntimo{}",
+            Currency::EGP | Currency::LBP | Currency::SDG | Currency::SSP | Currency::SYP => {
+                "piastra{}"
+            }
+            Currency::BYN | Currency::RUB => "kópek{}",
             _ => return currency.default_subunit_string("centavo{}", plural_form),
         }
         .replace("{}", if plural_form { "s" } else { "" })
@@ -513,10 +708,10 @@ impl Spanish {
                     // Explanation: Odd triplets should always be read as thousand, so we
                     // don't need to say "un mil"
                     (_, i) if triplet == 1 && i > 0 => {
-                        if i % 2 == 0 {
-                            "un"
-                        } else {
+                        if self.milliard_index(i) == 1 {
                             ""
+                        } else {
+                            "un"
                         }
                     }
                     (1, _) if self.feminine => "una",
@@ -566,23 +761,21 @@ impl Spanish {
             5 : Quadrillion
             6 : Quintillion
             */
-            let milliard_index = if i % 2 == 0 { i / 2 + 1 } else { 1 };
-            // Triplet of the last iteration
-            let last_triplet = triplets.get(i + 1).copied().unwrap_or(0);
+            let milliard_index = self.milliard_index(i);
+            // Triplet of the last iteration, whose thousands belong to this milliard on the long
+            // scale
+            let last_triplet = match self.short_scale {
+                true => 0,
+                false => triplets.get(i + 1).copied().unwrap_or(0),
+            };
             if i == 0 {
                 continue;
             }
             // Add the next Milliard if there's any.
             if (triplet != 0) || (last_triplet != 0 && milliard_index > 1) {
-                if milliard_index > MILLARES.len() - 1 {
-                    return Err(Num2Err::CannotConvert);
-                }
                 // Boolean that checks if next Milliard is plural
                 let plural = triplet > 1 || last_triplet > 0;
-                match plural {
-                    false => words.push(String::from(MILLAR[milliard_index])),
-                    true => words.push(String::from(MILLARES[milliard_index])),
-                }
+                words.push(self.milliard(milliard_index, plural));
             }
         }
         // flavour the text when negative
@@ -642,6 +835,145 @@ impl Spanish {
         }
     }
 
+    // Cardinals ending in "uno" agree with the noun that follows them, e.g. "veintiún dólares" or
+    // "veintiuna libras"
+    fn strip_uno_into_un_or_una(&self, string: String, noun: &str) -> String {
+        let has_feminine_noun = noun.ends_with('a') || noun.ends_with("as");
+        self.agree_uno_with_gender(string, has_feminine_noun)
+    }
+
+    fn whole_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let name = self.currencies(currency, num.int() != 1.into());
+        let cardinal = self.currency_cardinal(num, currency, false, &name)?;
+        Ok(count(&cardinal, &name))
+    }
+
+    /// Cardinal agreeing with the gender of a custom currency's unit or subunit, or else guessed
+    /// from its name
+    fn currency_cardinal(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+        subunit: bool,
+        name: &str,
+    ) -> Result<String, Num2Err> {
+        match currency.custom_nouns(Lang::Spanish) {
+            Some((unit, cent)) => {
+                let feminine = if subunit { cent } else { unit }.is_feminine();
+                let cardinal = self.clone().with_feminine(feminine).int_to_cardinal(num)?;
+                Ok(self.agree_uno_with_gender(cardinal, feminine))
+            }
+            None => Ok(self.strip_uno_into_un_or_una(self.int_to_cardinal(num)?, name)),
+        }
+    }
+
+    fn agree_uno_with_gender(&self, string: String, has_feminine_noun: bool) -> String {
+        if let Some(prefix) = string.strip_suffix("iuno") {
+            if has_feminine_noun {
+                format!("{prefix}iuna")
+            } else {
+                format!("{prefix}iún")
+            }
+        } else if let Some(prefix) = string.strip_suffix("uno") {
+            if has_feminine_noun {
+                format!("{prefix}una")
+            } else {
+                format!("{prefix}un")
+            }
+        } else {
+            string
+        }
+    }
+
+    fn unit_noun(&self, unit: Unit) -> Noun {
+        let (singular, plural, gender) = match unit {
+            Unit::Kilometre => ("kilómetro", "kilómetros", Gender::Masculine),
+            Unit::Metre => ("metro", "metros", Gender::Masculine),
+            Unit::Centimetre => ("centímetro", "centímetros", Gender::Masculine),
+            Unit::Millimetre => ("milímetro", "milímetros", Gender::Masculine),
+            Unit::Mile => ("milla", "millas", Gender::Feminine),
+            Unit::Foot => ("pie", "pies", Gender::Masculine),
+            Unit::Inch => ("pulgada", "pulgadas", Gender::Feminine),
+            Unit::Tonne => ("tonelada", "toneladas", Gender::Feminine),
+            Unit::Kilogram => ("kilogramo", "kilogramos", Gender::Masculine),
+            Unit::Gram => ("gramo", "gramos", Gender::Masculine),
+            Unit::Pound => ("libra", "libras", Gender::Feminine),
+            Unit::Litre => ("litro", "litros", Gender::Masculine),
+            Unit::Millilitre => ("mililitro", "mililitros", Gender::Masculine),
+            Unit::Celsius => ("grado centígrado", "grados centígrados", Gender::Masculine),
+            Unit::Fahrenheit => ("grado Fahrenheit", "grados Fahrenheit", Gender::Masculine),
+            Unit::KilometrePerHour => (
+                "kilómetro por hora",
+                "kilómetros por hora",
+                Gender::Masculine,
+            ),
+            Unit::MilePerHour => ("milla por hora", "millas por hora", Gender::Feminine),
+        };
+        Noun::new(singular, plural).with_gender(gender)
+    }
+
+    // Partitive word used as the denominator of a fraction, e.g. "quinto" or "onceavo"
+    fn fraction_denominator(
+        &self,
+        denominator: BigFloat,
+        plural_form: bool,
+    ) -> Result<String, Num2Err> {
+        let word = match denominator.to_u64() {
+            Some(2) => String::from("medio"),
+            Some(3) => String::from("tercio"),
+            Some(d @ 4..=9) => String::from(ordinal::UNIDADES[d as usize]) + "o",
+            Some(10) => String::from("décimo"),
+            Some(100) => String::from("centésimo"),
+            Some(1000) => String::from("milésimo"),
+            _ => {
+                let join = |cardinal: &str| {
+                    let cardinal = cardinal
+                        .replace(" y ", "i")
+                        .replace(' ', "")
+                        .replace('á', "a")
+                        .replace('é', "e")
+                        .replace('í', "i")
+                        .replace('ó', "o")
+                        .replace('ú', "u");
+                    match cardinal.strip_suffix("uno") {
+                        Some(prefix) => format!("{prefix}un"),
+                        None => cardinal,
+                    }
+                };
+                // multiples of a thousand, a million and above are read with the ordinal of the
+                // scale, the cardinal multiplying it being joined to it, e.g. "dosmilésimo",
+                // "millonésimo" or "veintiunmilésimo"
+                let ordinal = match denominator >= BigFloat::from(1000) {
+                    true => self.to_ordinal(denominator)?,
+                    false => String::new(),
+                };
+                match ordinal.rsplit_once(' ') {
+                    None if ordinal.ends_with("ésimo") => ordinal,
+                    Some((cardinal, scale))
+                        if scale.ends_with("ésimo") && !cardinal.contains("ésim") =>
+                    {
+                        format!("{}{scale}", join(cardinal))
+                    }
+                    // from https://www.rae.es/dpd/-avo: the cardinal is joined and suffixed with
+                    // -avo, e.g. "treintaidosavo" or "dieciseisavo"
+                    _ => {
+                        let cardinal = join(&self.int_to_cardinal(denominator)?);
+                        match cardinal.strip_suffix("nta") {
+                            Some(prefix) => format!("{prefix}ntavo"),
+                            None => format!("{cardinal}avo"),
+                        }
+                    }
+                }
+            }
+        };
+
+        if plural_form {
+            Ok(format!("{word}s"))
+        } else {
+            Ok(word)
+        }
+    }
+
     #[inline(always)]
     fn flavourize_with_negative(&self, words: &mut Vec<String>, flavour: NegativeFlavour) {
         use NegativeFlavour::*;
@@ -760,15 +1092,15 @@ impl Language for Spanish {
                 continue;
             }
 
-            let milliard_index = if i % 2 == 0 { i / 2 + 1 } else { 1 };
+            let milliard_index = self.milliard_index(i);
             // Triplet of the last iteration
-            let last_triplet = triplets.get(i + 1).copied().unwrap_or(0);
+            let last_triplet = match self.short_scale {
+                true => 0,
+                false => triplets.get(i + 1).copied().unwrap_or(0),
+            };
 
             // Add the next Milliard if there's any.
             if (triplet != 0) || (last_triplet != 0 && milliard_index > 1) {
-                if milliard_index > MILLARES.len() - 1 {
-                    return Err(Num2Err::CannotConvert);
-                }
                 if milliard_index == 1 && i > 1 {
                     // If we're indexing the thousand Milliard index we skip it
                     // because We will manually append it at the next milliard
@@ -780,13 +1112,15 @@ impl Language for Spanish {
                 // billones, etc., en la práctica inusitados, se forman prefijando al ordinal
                 // simple el cardinal que lo multiplica, y posponiendo los ordinales
                 // correspondientes a los órdenes inferiores```
+                // The cardinal multiplying the milliard doesn't agree with the ordinal's gender
+                let cardinal = self.clone().with_feminine(false);
                 let triplet_word = match triplet {
                     // I couldn't find any hard evidence whether bigger than single digits triplets
                     // should also be mono-worded with the milliard, so I'll assume they don't until
                     // otherwise because this way, something like "ciento unomilesima"(101_000)
                     // won't accidentally be misinterpreted as "1_000".
-                    10.. => self.to_cardinal(triplet.into())? + " ",
-                    2.. => self.to_cardinal(triplet.into())?,
+                    10.. => cardinal.to_cardinal(triplet.into())? + " ",
+                    2.. => cardinal.to_cardinal(triplet.into())?,
                     _ => String::from(""),
                 };
                 // ciento cuarenta y uno  milcien millonésimo doscientos once milésimo
@@ -795,8 +1129,10 @@ impl Language for Spanish {
                 // ciento cuarenta y uno milcienmillonésimo doscientos oncemilésimo vigesimoprimero
                 let get_last_triplet = || -> Result<String, Num2Err> {
                     match last_triplet {
-                        10.. => self.to_cardinal(last_triplet.into()).map(|word| word + " "),
-                        2.. => self.to_cardinal(last_triplet.into()),
+                        10.. => cardinal
+                            .to_cardinal(last_triplet.into())
+                            .map(|word| word + " "),
+                        2.. => cardinal.to_cardinal(last_triplet.into()),
                         _ => Ok(String::from("")),
                     }
                 };
@@ -809,7 +1145,10 @@ impl Language for Spanish {
                     "{}{}{}{}",
                     thousand_of_milliard,
                     triplet_word,
-                    MILLARES[milliard_index],
+                    match MILLARES.get(milliard_index) {
+                        Some(millares) => String::from(*millares),
+                        None => format!("{}illonésim", illion_stem(milliard_index - 1)),
+                    },
                     gender()
                 ));
             }
@@ -910,26 +1249,7 @@ impl Language for Spanish {
     /// assert_eq!(words, "un dólar estadounidense");
     /// ```
     fn to_currency(&self, num: BigFloat, currency: crate::Currency) -> Result<String, Num2Err> {
-        let strip_uno_into_un_or_una = |string: String, currency_suffix: &str| -> String {
-            let has_feminine_currency_suffix =
-                currency_suffix.ends_with('a') || currency_suffix.ends_with("as");
-            if let Some(prefix) = string.strip_suffix("iuno") {
-                if has_feminine_currency_suffix {
-                    format!("{prefix}iuna")
-                } else {
-                    format!("{prefix}iún")
-                }
-            } else if let Some(prefix) = string.strip_suffix("uno") {
-                if has_feminine_currency_suffix {
-                    format!("{prefix}una")
-                } else {
-                    format!("{prefix}un")
-                }
-            } else {
-                string
-            }
-        };
-
+        let both = self.both_parts && currency.minor_units() > 0;
         if num.is_nan() {
             Err(Num2Err::CannotConvert)
         } else if num.is_inf() {
@@ -937,31 +1257,392 @@ impl Language for Spanish {
             let inf = self.inf_to_cardinal(&num)? + "de {}";
             let word = inf.replace("{}", &currency);
             Ok(word)
-        } else if num.frac().is_zero() {
-            let is_plural = num.int() != 1.into();
-            let currency = self.currencies(currency, is_plural);
-            let cardinal = strip_uno_into_un_or_una(self.int_to_cardinal(num)?, &currency);
-            Ok(format!("{cardinal} {currency}"))
+        } else if num.frac().is_zero() && !both {
+            self.whole_currency(num, currency)
         } else {
-            let hundred: BigFloat = 100.into();
-            let (integral, cents) = (num.int(), num.mul(&hundred).int().rem(&hundred));
+            let (integral, cents) = split_minor(num, currency);
             let cents_is_plural = cents != 1.into();
             let cents_suffix = self.cents(currency, cents_is_plural);
             let (int_words, cent_words) = (
-                self.to_currency(integral, currency)?,
-                strip_uno_into_un_or_una(self.int_to_cardinal(cents)?, &cents_suffix),
+                self.whole_currency(integral, currency)?,
+                self.currency_cardinal(cents, currency, true, &cents_suffix)?,
             );
 
-            if cents.is_zero() {
+            if cents.is_zero() && !both {
                 Ok(int_words)
-            } else if integral.is_zero() {
+            } else if integral.is_zero() && !both {
                 Ok(format!("{cent_words} {cents_suffix}"))
             } else {
-                Ok(format!("{int_words} con {cent_words} {cents_suffix}"))
+                Ok(join_parts(
+                    &int_words,
+                    self.conjunction.unwrap_or("con"),
+                    &format!("{cent_words} {cents_suffix}"),
+                ))
+            }
+        }
+    }
+
+    /// An amount as written on cheques and invoices. Mexican pesos are the national currency, read
+    /// as "pesos" and followed by "M.N." (moneda nacional)
+    /// ```rust
+    /// use num2words::{Currency, Lang, Num2Words};
+    ///
+    /// let words = Num2Words::new(42.01)
+    ///     .lang(Lang::Spanish)
+    ///     .cheque(Currency::MXN)
+    ///     .to_words()
+    ///     .unwrap();
+    /// assert_eq!(words, "cuarenta y dos pesos 01/100 M.N.");
+    /// ```
+    fn to_cheque(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+        cents: Option<ChequeCents>,
+        only: bool,
+    ) -> Result<String, Num2Err> {
+        let national = matches!(currency, Currency::MXN);
+        let currency = if national { Currency::PESO } else { currency };
+        let (whole, cents_nb) = split_minor(num, currency);
+        let mut words = vec![];
+        match cents.unwrap_or(ChequeCents::Fraction) {
+            ChequeCents::Words => words.push(self.to_currency(num, currency)?),
+            ChequeCents::Digits => {
+                words.push(self.to_currency(whole, currency)?);
+                words.push(format!("con {}", minor_digits(cents_nb, currency)));
+                words.push(self.cents(currency, cents_nb != BigFloat::from(1)));
+            }
+            ChequeCents::Fraction => {
+                words.push(self.to_currency(whole, currency)?);
+                words.push(minor_fraction(cents_nb, currency));
+            }
+        }
+        if national {
+            words.push(String::from("M.N."));
+        }
+        if only {
+            words.push(String::from("exactamente"));
+        }
+        Ok(words.join(" "))
+    }
+
+    /// A Cardinal number followed by its partitive denominator. Improper fractions are read as
+    /// mixed numbers
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::new(3).lang(Lang::Spanish).fraction(4).to_words().unwrap();
+    /// assert_eq!(words, "tres cuartos");
+    ///
+    /// let words = Num2Words::new(21).lang(Lang::Spanish).fraction(8).to_words().unwrap();
+    /// assert_eq!(words, "dos y cinco octavos");
+    /// ```
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err> {
+        // Fractions are masculine nouns, e.g. "un cuarto"
+        let lang = self.clone().with_feminine(false);
+        let is_negative = numerator.is_negative();
+        let numerator = numerator.abs();
+        let whole = (numerator / denominator).int();
+        let rest = numerator % denominator;
+
+        let mut words = vec![];
+        if !whole.is_zero() {
+            words.push(lang.int_to_cardinal(whole)?);
+        }
+        if !rest.is_zero() || whole.is_zero() {
+            let one = BigFloat::from(1);
+            let denominator_word = lang.fraction_denominator(denominator, rest != one)?;
+            if !whole.is_zero() {
+                words.push(String::from("y"));
             }
+            if whole.is_zero() || rest != one || denominator != BigFloat::from(2) {
+                // case `2 1/2` => `dos y medio`
+                let rest_word = lang.int_to_cardinal(rest)?;
+                words.push(lang.strip_uno_into_un_or_una(rest_word, &denominator_word));
+            }
+            words.push(denominator_word);
+        }
+        if is_negative {
+            self.flavourize_with_negative(&mut words, self.neg_flavour);
         }
+        Ok(words.join(" "))
+    }
+    /// The leading part followed by the largest scale word, e.g. "dos punto cinco millones"
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::new(2_500_000).lang(Lang::Spanish).prefer("half").compact(3);
+    /// assert_eq!(words.to_words().unwrap(), "dos millones y medio");
+    /// let words = Num2Words::new(3e9).lang(Lang::Spanish).compact(3).to_words();
+    /// assert_eq!(words.unwrap(), "tres mil millones");
+    /// ```
+    fn to_compact(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+    ) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if approximate {
+            words.push(String::from("alrededor de"));
+        }
+        if scale == 0 {
+            words.push(self.to_cardinal(leading)?);
+            return Ok(words.join(" "));
+        }
+        // the long scale steps by a million, odd scales being a thousand of them, e.g. "mil
+        // millones"
+        let index = match self.short_scale {
+            true => scale,
+            false => scale / 2 + 1,
+        };
+        let thousands = scale == 1 || (scale % 2 == 1 && !self.short_scale);
+        let noun = match (index, thousands) {
+            (1, _) => Noun::new(String::from("mil"), String::from("mil")),
+            (_, true) => {
+                let mega = format!("mil {}", self.milliard(index, true));
+                Noun::new(mega.clone(), mega)
+            }
+            _ => Noun::new(self.milliard(index, false), self.milliard(index, true)),
+        };
+
+        let half = self.prefer_half && leading.frac().abs() == BigFloat::from(0.5);
+        let count = if half { leading.int() } else { leading };
+        words.push(match count.to_u64() {
+            // "mil millones", not "un mil millones"
+            Some(1) if thousands => noun.plural.clone(),
+            _ => self.to_counted(count, &noun)?,
+        });
+        if half {
+            words.push(String::from("y medio"));
+        }
+        Ok(words.join(" "))
+    }
+
+    /// The currency follows the scale word, through "de" after "millón" and above
+    /// ```rust
+    /// use num2words::{Currency, Lang, Num2Words};
+    ///
+    /// let words = Num2Words::new(2_500_000)
+    ///     .lang(Lang::Spanish)
+    ///     .prefer("coma")
+    ///     .compact_currency(Currency::USD, 3)
+    ///     .to_words();
+    /// assert_eq!(words.unwrap(), "dos coma cinco millones de dólares estadounidenses");
+    /// ```
+    fn to_compact_currency(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        let noun = self.currency_noun(currency, false)?;
+        if scale == 0 {
+            let prefix = if approximate { "alrededor de " } else { "" };
+            return Ok(format!("{prefix}{}", self.to_counted(leading, &noun)?));
+        }
+        let words = self.to_compact(leading, scale, approximate)?;
+        let name = noun.form(true);
+        Ok(match scale {
+            1 => format!("{words} {name}"),
+            _ => format!("{words} de {name}"),
+        })
+    }
+
+    /// A Cardinal number followed by "por ciento"
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::new(12.5).lang(Lang::Spanish).prefer("coma").percent().to_words();
+    /// assert_eq!(words.unwrap(), "doce coma cinco por ciento");
+    /// ```
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} por ciento", self.to_cardinal(num)?))
+    }
+
+    /// A Cardinal number followed by "por mil"
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::new(3).lang(Lang::Spanish).permille().to_words();
+    /// assert_eq!(words.unwrap(), "tres por mil");
+    /// ```
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} por mil", self.to_cardinal(num)?))
+    }
+
+    /// The mantissa "por diez elevado a" the exponent
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::parse("1.6e-19").unwrap().lang(Lang::Spanish).scientific().to_words();
+    /// assert_eq!(words.unwrap(), "uno punto seis por diez elevado a menos diecinueve");
+    /// ```
+    fn to_scientific(&self, mantissa: BigFloat, exponent: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} por diez elevado a {}",
+            self.to_cardinal(mantissa)?,
+            self.to_cardinal(exponent)?
+        ))
+    }
+
+    /// Hours are feminine ("la una", "las veintiuna") and the part of the day is appended on a
+    /// 12-hour clock
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::new(14).lang(Lang::Spanish).time(5, None).to_words();
+    /// assert_eq!(words.unwrap(), "las dos y cinco de la tarde");
+    /// let words = Num2Words::new(14).lang(Lang::Spanish).prefer("24h").time(5, None).to_words();
+    /// assert_eq!(words.unwrap(), "las catorce y cinco");
+    /// ```
+    fn to_time(&self, hours: u8, minutes: u8, seconds: Option<u8>) -> Result<String, Num2Err> {
+        let lang = self.clone().with_feminine(false);
+        let mut words = vec![];
+
+        let clock_hours = match hours % 12 {
+            _ if self.prefer_24h => hours,
+            0 => 12,
+            h => h,
+        };
+        let hour_word = lang.int_to_cardinal(BigFloat::from(clock_hours))?;
+        words.push(String::from(if clock_hours == 1 { "la" } else { "las" }));
+        words.push(self.strip_uno_into_un_or_una(hour_word, "horas"));
+
+        match minutes {
+            0 => (),
+            15 if !self.prefer_24h => words.push(String::from("y cuarto")),
+            30 if !self.prefer_24h => words.push(String::from("y media")),
+            _ => {
+                words.push(String::from("y"));
+                words.push(lang.int_to_cardinal(BigFloat::from(minutes))?);
+            }
+        }
+
+        if let Some(seconds) = seconds.filter(|&s| s != 0) {
+            let noun = if seconds == 1 { "segundo" } else { "segundos" };
+            let cardinal = lang.int_to_cardinal(BigFloat::from(seconds))?;
+            words.push(String::from("con"));
+            words.push(self.strip_uno_into_un_or_una(cardinal, noun));
+            words.push(String::from(noun));
+        }
+
+        if !self.prefer_24h {
+            words.push(String::from(match hours {
+                1..=5 => "de la madrugada",
+                6..=11 => "de la mañana",
+                12 => "del mediodía",
+                13..=19 => "de la tarde",
+                _ => "de la noche",
+            }));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    /// Days are read as cardinals, e.g. "tres de marzo de mil novecientos uno"
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::new(1901).lang(Lang::Spanish).date(3, 3).to_words();
+    /// assert_eq!(words.unwrap(), "tres de marzo de mil novecientos uno");
+    /// ```
+    fn to_date(&self, year: BigFloat, month: u8, day: u8) -> Result<String, Num2Err> {
+        let lang = self.clone().with_feminine(false);
+        Ok(format!(
+            "{} de {} de {}",
+            lang.int_to_cardinal(BigFloat::from(day))?,
+            MESES[month as usize - 1],
+            lang.to_year(year)?
+        ))
+    }
+
+    /// Ordinals up to ten, then cardinals, e.g. "Alfonso décimo" but "Alfonso trece"
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::parse_regnal("Juan Pablo II").unwrap().lang(Lang::Spanish).to_words();
+    /// assert_eq!(words.unwrap(), "Juan Pablo segundo");
+    /// let words = Num2Words::parse_regnal("Alfonso XIII").unwrap().lang(Lang::Spanish).to_words();
+    /// assert_eq!(words.unwrap(), "Alfonso trece");
+    /// ```
+    fn to_regnal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num <= BigFloat::from(10) {
+            self.clone().with_plural(false).to_ordinal(num)
+        } else {
+            self.clone().with_feminine(false).int_to_cardinal(num)
+        }
+    }
+
+    /// The noun is plural unless the number is one, and a cardinal ending in "uno" agrees with the
+    /// gender of the noun
+    /// ```rust
+    /// use num2words::{Lang, Num2Words, Unit};
+    ///
+    /// let words = Num2Words::new(21).lang(Lang::Spanish).unit(Unit::Tonne).to_words();
+    /// assert_eq!(words.unwrap(), "veintiuna toneladas");
+    /// let words = Num2Words::new(1.5).lang(Lang::Spanish).unit(Unit::Litre).to_words();
+    /// assert_eq!(words.unwrap(), "uno punto cinco litros");
+    /// ```
+    fn to_counted(&self, num: BigFloat, noun: &Noun) -> Result<String, Num2Err> {
+        let lang = self.clone().with_feminine(noun.is_feminine());
+        let mut cardinal = lang.to_cardinal(num)?;
+        if num.frac().is_zero() {
+            cardinal = self.agree_uno_with_gender(cardinal, noun.is_feminine());
+        }
+        Ok(count(&cardinal, noun.form(num.abs() != BigFloat::from(1))))
+    }
+
+    fn currency_noun(&self, currency: Currency, subunit: bool) -> Result<Noun, Num2Err> {
+        if let Some((unit, cent)) = currency.custom_nouns(Lang::Spanish) {
+            return Ok(if subunit { cent } else { unit });
+        }
+        let name = |plural_form| match subunit {
+            true => self.cents(currency, plural_form),
+            false => self.currencies(currency, plural_form),
+        };
+        // the gender is guessed from the name, as for the amounts, e.g. "una libra"
+        let singular = name(false);
+        let gender = match singular.ends_with('a') {
+            true => Gender::Feminine,
+            false => Gender::Masculine,
+        };
+        Ok(Noun::new(singular, name(true)).with_gender(gender))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.to_counted(num, &self.unit_noun(unit))
+    }
+
+    /// Each digit is read on its own, or by chunks with the "pairs" or "triples" preferences
+    /// ```rust
+    /// use num2words::{Lang, Num2Words};
+    ///
+    /// let words = Num2Words::parse_digits("555-0142").unwrap().lang(Lang::Spanish).to_words();
+    /// assert_eq!(words.unwrap(), "cinco cinco cinco cero uno cuatro dos");
+    /// ```
+    fn to_digits(&self, groups: &[String]) -> Result<String, Num2Err> {
+        let lang = self.clone().with_feminine(false);
+        let mut words = vec![];
+        for group in groups {
+            for chunk in digit_chunks(group, self.digit_group.max(1)) {
+                words.extend(read_digit_chunk(chunk, |n| lang.int_to_cardinal(n))?);
+            }
+        }
+        Ok(words.join(" "))
+    }
+}
+/// Cardinal followed by the counted noun, through "de" when the cardinal ends with a scale noun,
+/// e.g. "un millón de libros"
+fn count(cardinal: &str, name: &str) -> String {
+    if cardinal.ends_with("llón") || cardinal.ends_with("llones") {
+        format!("{cardinal} de {name}")
+    } else {
+        format!("{cardinal} {name}")
     }
 }
+
 // TODO: Remove Copy trait if enums can store data
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NegativeFlavour {
@@ -1233,10 +1914,14 @@ mod tests {
              cincuenta y siete mil quinientos cuarenta y cuatro quindeciollones setecientos \
              ochenta y cuatro mil trescientos cuatrodecillones"
         );
-        let too_big_num = BigFloat::from_u8(2).pow(&BigFloat::from_u16(419));
+        // beyond vigintillones, the names are systematic
+        let bigger_num = BigFloat::from_u8(2).pow(&BigFloat::from_u16(419));
+        assert!(to_cardinal(&es, to(bigger_num))
+            .unwrap()
+            .starts_with("un unvigintillón trescientos cincuenta y tres mil"));
         assert_eq!(
-            to_cardinal(&es, to(too_big_num)).unwrap_err(),
-            Num2Err::CannotConvert
+            Language::to_ordinal(&es, to(BigFloat::parse("1e126").unwrap())).unwrap(),
+            "unvigintillonésimo"
         );
 
         let to_ordinal = Language::to_ordinal;
@@ -1384,6 +2069,390 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lang_es_time() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_time(14, 5, None).unwrap(),
+            "las dos y cinco de la tarde"
+        );
+        assert_eq!(
+            es.to_time(1, 15, None).unwrap(),
+            "la una y cuarto de la madrugada"
+        );
+        assert_eq!(
+            es.to_time(0, 30, None).unwrap(),
+            "las doce y media de la noche"
+        );
+        assert_eq!(es.to_time(12, 0, None).unwrap(), "las doce del mediodía");
+        assert_eq!(
+            es.to_time(23, 59, Some(21)).unwrap(),
+            "las once y cincuenta y nueve con veintiún segundos de la noche"
+        );
+
+        let es = es.with_24h(true);
+        assert_eq!(es.to_time(21, 15, None).unwrap(), "las veintiuna y quince");
+        assert_eq!(
+            es.to_time(9, 0, Some(1)).unwrap(),
+            "las nueve con un segundo"
+        );
+    }
+
+    #[test]
+    fn lang_es_date() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_date(to(1901), 3, 3).unwrap(),
+            "tres de marzo de mil novecientos uno"
+        );
+        assert_eq!(
+            es.to_date(to(2021), 5, 21).unwrap(),
+            "veintiuno de mayo de dos mil veintiuno"
+        );
+        assert_eq!(
+            es.to_date(to(-44), 3, 15).unwrap(),
+            "quince de marzo de cuarenta y cuatro a. C."
+        );
+        assert_eq!(es.to_date(to(0), 1, 1), Err(Num2Err::CannotConvert));
+    }
+
+    #[test]
+    fn lang_es_digits() {
+        let es = Spanish::default().with_digit_group(2);
+        let groups = [String::from("91"), String::from("5550142")];
+        assert_eq!(
+            es.to_digits(&groups).unwrap(),
+            "noventa y uno cinco cincuenta y cinco cero uno cuarenta y dos"
+        );
+        let es = es.with_digit_group(3);
+        assert_eq!(
+            es.to_digits(&groups).unwrap(),
+            "noventa y uno cinco quinientos cincuenta ciento cuarenta y dos"
+        );
+    }
+
+    #[test]
+    fn lang_es_short_scale() {
+        let es = Spanish::default().with_scale(Scale::Short);
+        assert_eq!(es.to_cardinal(to(1e9)).unwrap(), "un billón");
+        assert_eq!(es.to_cardinal(to(1e12)).unwrap(), "un trillón");
+        assert_eq!(
+            es.to_cardinal(to(5_001_000_000.0)).unwrap(),
+            "cinco billones un millón"
+        );
+        assert_eq!(es.to_cardinal(to(1_001_000.0)).unwrap(), "un millón mil");
+        assert_eq!(es.to_ordinal(to(1e9)).unwrap(), "billonésimo");
+        assert_eq!(
+            es.to_compact(to(2.5), 3, false).unwrap(),
+            "dos punto cinco billones"
+        );
+        assert_eq!(es.to_compact(to(1), 1, false).unwrap(), "mil");
+    }
+
+    #[test]
+    fn lang_es_currency_names() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_currency(to(2), Currency::MAD).unwrap(),
+            "dos dírhams marroquíes"
+        );
+        assert_eq!(
+            es.to_currency(to(1.5), Currency::EGP).unwrap(),
+            "una libra egipcia con cincuenta piastras"
+        );
+        assert_eq!(
+            es.to_currency(to(21), Currency::SEK).unwrap(),
+            "veintiuna coronas suecas"
+        );
+        assert_eq!(
+            es.to_currency(to(3), Currency::XAU).unwrap(),
+            "tres onzas troy de oro"
+        );
+        assert_eq!(
+            es.to_currency(to(2), Currency::XDR).unwrap(),
+            "dos derechos especiales de giro"
+        );
+
+        // every name is translated, apart from the single words borrowed as is, like euro, and the
+        // Spanish names of the Latin American units of account
+        let borrowed = [Currency::CLF, Currency::COU, Currency::UYW];
+        for &currency in Currency::all() {
+            for plural_form in [false, true] {
+                let english = currency.default_string(plural_form);
+                assert!(
+                    !english.contains(' ')
+                        || borrowed.contains(&currency)
+                        || es.currencies(currency, plural_form) != english,
+                    "{:?} has no Spanish name",
+                    currency
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lang_es_minor_units() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_currency(to(1.234), Currency::KWD).unwrap(),
+            "un dinar kuwaití con doscientos treinta y cuatro fils"
+        );
+        assert_eq!(es.to_currency(to(7), Currency::JPY).unwrap(), "siete yenes");
+        assert_eq!(
+            es.to_cheque(to(1.234), Currency::KWD, None, false).unwrap(),
+            "un dinar kuwaití 234/1000"
+        );
+    }
+
+    #[test]
+    fn lang_es_custom_currency() {
+        let es = Spanish::default();
+        let peseta = crate::CustomCurrency::new("ES_PTA", 2)
+            .with_names(
+                Lang::Spanish,
+                Noun::new("peseta", "pesetas").with_gender(Gender::Feminine),
+                Noun::new("céntimo", "céntimos"),
+            )
+            .register();
+        assert_eq!(es.to_currency(to(1), peseta).unwrap(), "una peseta");
+        assert_eq!(
+            es.to_currency(to(221.21), peseta).unwrap(),
+            "doscientas veintiuna pesetas con veintiún céntimos"
+        );
+        let points = crate::CustomCurrency::new("ES_PTS", 0)
+            .with_names(
+                Lang::English,
+                Noun::new("point", "points"),
+                Noun::new("", ""),
+            )
+            .register();
+        assert_eq!(es.to_currency(to(21), points).unwrap(), "veintiún points");
+    }
+
+    #[test]
+    fn lang_es_compact_currency() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_compact_currency(to(2.5), 2, false, Currency::DOLLAR)
+                .unwrap(),
+            "dos punto cinco millones de dólares"
+        );
+        assert_eq!(
+            es.to_compact_currency(to(3), 3, false, Currency::EUR)
+                .unwrap(),
+            "tres mil millones de euros"
+        );
+        assert_eq!(
+            es.to_compact_currency(to(21), 1, false, Currency::EUR)
+                .unwrap(),
+            "veintiún mil euros"
+        );
+        assert_eq!(
+            es.to_compact_currency(to(21), 0, false, Currency::GBP)
+                .unwrap(),
+            "veintiuna libras"
+        );
+    }
+
+    #[test]
+    fn lang_es_regions() {
+        let mx = Spanish::default().with_region(Some(RegionSpanish::MX));
+        assert_eq!(mx.to_currency(to(2), Currency::MXN).unwrap(), "dos pesos");
+        assert_eq!(
+            mx.to_currency(to(2), Currency::ARS).unwrap(),
+            "dos pesos argentinos"
+        );
+        let ar = Spanish::default().with_region(Some(RegionSpanish::AR));
+        assert_eq!(
+            ar.to_currency(to(1.5), Currency::ARS).unwrap(),
+            "un peso con cincuenta centavos"
+        );
+        let es = Spanish::default().with_region(Some(RegionSpanish::ES));
+        assert_eq!(
+            es.to_currency(to(2), Currency::MXN).unwrap(),
+            "dos pesos mexicanos"
+        );
+        assert_eq!(RegionSpanish::ES.decimal_char(), DecimalChar::Coma);
+        assert_eq!(RegionSpanish::MX.decimal_char(), DecimalChar::Punto);
+    }
+
+    #[test]
+    fn lang_es_currency_parts() {
+        let es = Spanish::default().with_currency_parts(true, Some("y"));
+        assert_eq!(
+            es.to_currency(to(1), Currency::EUR).unwrap(),
+            "un euro y cero céntimos"
+        );
+        assert_eq!(
+            es.to_currency(to(0.21), Currency::EUR).unwrap(),
+            "cero euros y veintiún céntimos"
+        );
+        let es = Spanish::default().with_currency_parts(false, Some(""));
+        assert_eq!(
+            es.to_currency(to(1.5), Currency::EUR).unwrap(),
+            "un euro cincuenta céntimos"
+        );
+    }
+
+    #[test]
+    fn lang_es_cheque() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_cheque(to(42.01), Currency::MXN, None, false).unwrap(),
+            "cuarenta y dos pesos 01/100 M.N."
+        );
+        assert_eq!(
+            es.to_cheque(to(21), Currency::MXN, None, true).unwrap(),
+            "veintiún pesos 00/100 M.N. exactamente"
+        );
+        assert_eq!(
+            es.to_cheque(to(1.5), Currency::EUR, Some(ChequeCents::Digits), false)
+                .unwrap(),
+            "un euro con 50 céntimos"
+        );
+        assert_eq!(
+            es.to_cheque(to(42.01), Currency::EUR, Some(ChequeCents::Words), true)
+                .unwrap(),
+            "cuarenta y dos euros con un céntimo exactamente"
+        );
+    }
+
+    #[test]
+    fn lang_es_compact() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_compact(to(2.5), 2, false).unwrap(),
+            "dos punto cinco millones"
+        );
+        assert_eq!(es.to_compact(to(1), 2, false).unwrap(), "un millón");
+        assert_eq!(es.to_compact(to(1), 1, false).unwrap(), "mil");
+        assert_eq!(es.to_compact(to(1), 3, false).unwrap(), "mil millones");
+        assert_eq!(
+            es.to_compact(to(21), 4, true).unwrap(),
+            "alrededor de veintiún billones"
+        );
+        let es = es.with_half(true);
+        assert_eq!(
+            es.to_compact(to(1.5), 2, false).unwrap(),
+            "un millón y medio"
+        );
+    }
+
+    #[test]
+    fn lang_es_scientific() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_scientific(to(6.022), to(23)).unwrap(),
+            "seis punto cero dos dos por diez elevado a veintitrés"
+        );
+        assert_eq!(
+            es.to_scientific(to(1), to(-9)).unwrap(),
+            "uno por diez elevado a menos nueve"
+        );
+    }
+
+    #[test]
+    fn lang_es_regnal() {
+        let es = Spanish::default();
+        assert_eq!(es.to_regnal(to(1)).unwrap(), "primero");
+        assert_eq!(es.to_regnal(to(10)).unwrap(), "décimo");
+        assert_eq!(es.to_regnal(to(13)).unwrap(), "trece");
+        let es = es.with_feminine(true);
+        assert_eq!(es.to_regnal(to(2)).unwrap(), "segunda");
+        assert_eq!(es.to_regnal(to(21)).unwrap(), "veintiuno");
+    }
+
+    #[test]
+    fn lang_es_unit() {
+        let es = Spanish::default();
+        assert_eq!(
+            es.to_unit(to(3), Unit::Celsius).unwrap(),
+            "tres grados centígrados"
+        );
+        assert_eq!(es.to_unit(to(1), Unit::Kilometre).unwrap(), "un kilómetro");
+        assert_eq!(es.to_unit(to(1), Unit::Mile).unwrap(), "una milla");
+        assert_eq!(
+            es.to_unit(to(31), Unit::Kilogram).unwrap(),
+            "treinta y un kilogramos"
+        );
+        assert_eq!(
+            es.to_unit(to(-1), Unit::Celsius).unwrap(),
+            "menos un grado centígrado"
+        );
+        assert_eq!(es.to_unit(to(0), Unit::Foot).unwrap(), "cero pies");
+    }
+
+    #[test]
+    fn lang_es_counted() {
+        let es = Spanish::default();
+        let libro = Noun::new("libro", "libros");
+        let casa = Noun::new("casa", "casas").with_gender(Gender::Feminine);
+        assert_eq!(es.to_counted(to(21), &libro).unwrap(), "veintiún libros");
+        assert_eq!(es.to_counted(to(21), &casa).unwrap(), "veintiuna casas");
+        assert_eq!(es.to_counted(to(1), &casa).unwrap(), "una casa");
+        assert_eq!(
+            es.to_counted(to(201), &casa).unwrap(),
+            "doscientas una casas"
+        );
+        assert_eq!(
+            es.to_counted(to(1_000_001), &libro).unwrap(),
+            "un millón un libros"
+        );
+        assert_eq!(
+            es.to_counted(to(2_500_000), &casa).unwrap(),
+            "dos millones quinientas mil casas"
+        );
+        assert_eq!(
+            es.to_counted(to(1_000_000), &libro).unwrap(),
+            "un millón de libros"
+        );
+        assert_eq!(
+            es.to_counted(to(2_000_000_000), &casa).unwrap(),
+            "dos mil millones de casas"
+        );
+        assert_eq!(
+            es.to_unit(to(1_000_000), Unit::Kilometre).unwrap(),
+            "un millón de kilómetros"
+        );
+        assert_eq!(
+            es.to_currency(to(2_000_000), Currency::EUR).unwrap(),
+            "dos millones de euros"
+        );
+    }
+
+    #[test]
+    fn lang_es_fraction() {
+        let es = Spanish::default();
+        let fraction = |n: i64, d: i64| es.to_fraction(to(n), to(d)).unwrap();
+        assert_eq!(fraction(3, 4), "tres cuartos");
+        assert_eq!(fraction(5, 8), "cinco octavos");
+        assert_eq!(fraction(1, 3), "un tercio");
+        assert_eq!(fraction(1, 10), "un décimo");
+        assert_eq!(fraction(7, 100), "siete centésimos");
+        assert_eq!(fraction(1, 11), "un onceavo");
+        assert_eq!(fraction(5, 16), "cinco dieciseisavos");
+        assert_eq!(fraction(21, 30), "veintiún treintavos");
+        assert_eq!(fraction(1, 32), "un treintaidosavo");
+        assert_eq!(fraction(3, 2000), "tres dosmilésimos");
+        assert_eq!(fraction(1, 21_000), "un veintiunmilésimo");
+        assert_eq!(fraction(1, 100_000), "un cienmilésimo");
+        assert_eq!(fraction(3, 1_000_000), "tres millonésimos");
+        assert_eq!(fraction(1, 2_000_000), "un dosmillonésimo");
+        assert_eq!(fraction(1, 1_000_000_000), "un milmillonésimo");
+        assert_eq!(fraction(1, 2100), "un dosmilcienavo");
+        assert_eq!(fraction(21, 8), "dos y cinco octavos");
+        assert_eq!(fraction(5, 2), "dos y medio");
+        assert_eq!(fraction(9, 4), "dos y un cuarto");
+        assert_eq!(fraction(-3, 4), "menos tres cuartos");
+        assert_eq!(
+            es.clone()
+                .with_feminine(true)
+                .to_fraction(to(1), to(4))
+                .unwrap(),
+            "un cuarto"
+        );
+    }
+
     #[test]
     fn lang_es_with_fraction() {
         use DecimalChar::{Coma, Punto};
@@ -1,10 +1,26 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::currency::inflect;
+use crate::lang::illion::illion_stem;
+use crate::lang::{
+    digit_chunks, join_parts, minor_digits, minor_fraction, read_digit_chunk, split_minor,
+    ChequeCents, Scale,
+};
+use crate::noun::{Gender, Noun};
+use crate::{num2words::Num2Err, Currency, Lang, Language, Unit};
 use num_bigfloat::BigFloat;
 
 pub struct French {
     feminine: bool,
     reformed: bool,
     region: RegionFrench,
+    tens: Tens,
+    virgule: bool,
+    prefer_24h: bool,
+    digit_group: usize,
+    prefer_half: bool,
+    scale: Scale,
+    both_parts: bool,
+    conjunction: Option<&'static str>,
+    prefer_rappen: bool,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
@@ -13,12 +29,66 @@ pub enum RegionFrench {
     FR,
     BE,
     CH,
+    CA,
+}
+
+impl RegionFrench {
+    /// Words of the region for seventy, eighty and ninety
+    pub fn tens(self) -> Tens {
+        match self {
+            RegionFrench::FR | RegionFrench::CA => Tens::default(),
+            RegionFrench::BE => Tens {
+                septante: true,
+                eighty: Eighty::QuatreVingts,
+                nonante: true,
+            },
+            RegionFrench::CH => Tens {
+                septante: true,
+                eighty: Eighty::Huitante,
+                nonante: true,
+            },
+        }
+    }
+}
+
+/// Words for seventy, eighty and ninety, "soixante-dix", "quatre-vingts" and
+/// "quatre-vingt-dix" unless they are replaced, e.g. by "septante"
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct Tens {
+    pub septante: bool,
+    pub eighty: Eighty,
+    pub nonante: bool,
+}
+
+/// Word for eighty, "huitante" in most Swiss cantons and "octante" in older
+/// usage
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum Eighty {
+    #[default]
+    QuatreVingts,
+    Huitante,
+    Octante,
 }
 
 const UNITS: [&str; 9] = [
     "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
 ];
 
+const MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
 const TENS: [&str; 9] = [
     "dix",
     "vingt",
@@ -78,62 +148,370 @@ impl French {
             feminine,
             reformed,
             region,
+            tens: region.tens(),
+            virgule: false,
+            prefer_24h: true,
+            digit_group: 1,
+            prefer_half: false,
+            scale: Scale::Long,
+            both_parts: false,
+            conjunction: None,
+            prefer_rappen: false,
+        }
+    }
+
+    pub fn with_tens(self, tens: Tens) -> Self {
+        Self { tens, ..self }
+    }
+
+    pub fn with_rappen(self, prefer_rappen: bool) -> Self {
+        Self {
+            prefer_rappen,
+            ..self
+        }
+    }
+
+    pub fn with_virgule(self, virgule: bool) -> Self {
+        Self { virgule, ..self }
+    }
+
+    pub fn with_24h(self, prefer_24h: bool) -> Self {
+        Self { prefer_24h, ..self }
+    }
+
+    pub fn with_digit_group(self, digit_group: usize) -> Self {
+        Self {
+            digit_group,
+            ..self
+        }
+    }
+
+    pub fn with_half(self, prefer_half: bool) -> Self {
+        Self {
+            prefer_half,
+            ..self
+        }
+    }
+
+    pub fn with_scale(self, scale: Scale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn with_currency_parts(self, both_parts: bool, conjunction: Option<&'static str>) -> Self {
+        Self {
+            both_parts,
+            conjunction,
+            ..self
         }
     }
 
+    /// Name of the given power of a thousand, the short scale skipping the
+    /// "-illiard" names, e.g. "billion" for the third one
+    fn mega(&self, power: usize) -> String {
+        let index = match self.scale {
+            Scale::Short if power > 1 => power * 2 - 3,
+            _ => power - 1,
+        };
+        if let Some(mega) = MEGAS.get(index) {
+            return String::from(*mega);
+        }
+
+        // systematic names beyond the table, e.g. "septendécillion"
+        let stem = illion_stem(index.div_ceil(2)).replace("dec", "déc");
+        match index % 2 {
+            1 => format!("{stem}illion"),
+            _ => format!("{stem}illiard"),
+        }
+    }
+
+    // heure, minute and seconde are feminine nouns, e.g. une heure
+    fn time_unit(&self, num: u8, noun: &str) -> Result<String, Num2Err> {
+        let lang = French::new(true, self.reformed, self.region).with_tens(self.tens);
+        Ok(format!(
+            "{} {noun}{}",
+            lang.int_to_cardinal(BigFloat::from(num))?,
+            if num > 1 { "s" } else { "" }
+        ))
+    }
+
+    fn unit_noun(&self, unit: Unit) -> Noun {
+        let (singular, plural, gender) = match unit {
+            Unit::Kilometre => ("kilomètre", "kilomètres", Gender::Masculine),
+            Unit::Metre => ("mètre", "mètres", Gender::Masculine),
+            Unit::Centimetre => ("centimètre", "centimètres", Gender::Masculine),
+            Unit::Millimetre => ("millimètre", "millimètres", Gender::Masculine),
+            Unit::Mile => ("mile", "miles", Gender::Masculine),
+            Unit::Foot => ("pied", "pieds", Gender::Masculine),
+            Unit::Inch => ("pouce", "pouces", Gender::Masculine),
+            Unit::Tonne => ("tonne", "tonnes", Gender::Feminine),
+            Unit::Kilogram => ("kilogramme", "kilogrammes", Gender::Masculine),
+            Unit::Gram => ("gramme", "grammes", Gender::Masculine),
+            Unit::Pound => ("livre", "livres", Gender::Feminine),
+            Unit::Litre => ("litre", "litres", Gender::Masculine),
+            Unit::Millilitre => ("millilitre", "millilitres", Gender::Masculine),
+            Unit::Celsius => ("degré Celsius", "degrés Celsius", Gender::Masculine),
+            Unit::Fahrenheit => ("degré Fahrenheit", "degrés Fahrenheit", Gender::Masculine),
+            Unit::KilometrePerHour => (
+                "kilomètre par heure",
+                "kilomètres par heure",
+                Gender::Masculine,
+            ),
+            Unit::MilePerHour => ("mile par heure", "miles par heure", Gender::Masculine),
+        };
+        Noun::new(singular, plural).with_gender(gender)
+    }
+
     fn get_tens(&self, tens: usize) -> &str {
-        match (tens, self.region) {
-            (7, RegionFrench::BE) | (7, RegionFrench::CH) => "septante",
-            (8, RegionFrench::CH) => "huitante",
-            (9, RegionFrench::BE) | (9, RegionFrench::CH) => "nonante",
+        match (tens, self.tens.eighty) {
+            (7, _) if self.tens.septante => "septante",
+            (8, Eighty::Huitante) => "huitante",
+            (8, Eighty::Octante) => "octante",
+            (9, _) if self.tens.nonante => "nonante",
             _ => TENS[tens - 1],
         }
     }
 
     fn currencies(&self, currency: Currency, plural_form: bool) -> String {
-        match currency {
-            Currency::ARS => String::from("peso{} argentin"),
-            Currency::AUD => String::from("dollar{} australien"),
-            Currency::BRL => String::from(if plural_form { "réaux" } else { "réal" }),
-            Currency::CAD => String::from("dollar{} canadien"),
-            Currency::CLP => String::from("peso{} chilien"),
-            Currency::COP => String::from("peso{} colombien"),
-            Currency::DZD => String::from("dinar{} algérien"),
-            Currency::GBP => String::from("livre{}"),
-            Currency::HKD => String::from("dollar{} de Hong Kong"),
-            Currency::IDR => String::from("roupie{} indonésienne"),
-            Currency::ILS => String::from("shekel{}"),
-            Currency::INR => String::from("roupie{}"),
-            Currency::KWD => String::from("dinar{} koweïtien"),
-            Currency::MXN => String::from("peso{} mexicain"),
-            Currency::NOK => String::from("couronne{} norvégienne"),
-            Currency::NZD => String::from("dollar{} néo-zélandais"),
-            Currency::PHP => String::from("peso{} phillippin"),
-            Currency::PLN => String::from("złoty{}"),
-            Currency::QAR => String::from("riyal{} qatarien"),
-            Currency::RUB => String::from("rouble{}"),
-            Currency::SAR => String::from("riyal{} saoudien"),
-            Currency::SGD => String::from("dollar{} de Singapour"),
-            Currency::THB => String::from("baht{}"),
-            Currency::TRY => String::from("lire{}"),
-            Currency::TWD => String::from("dollar{} de Taïwan"),
-            Currency::UAH => String::from("hryvnia{}"),
-            Currency::USD => String::from("dollar{} américain"),
-            Currency::UYU => String::from("peso{} uruguayen"),
-            _ => currency.default_string(plural_form),
+        if let Some((unit, _)) = currency.custom_nouns(Lang::French) {
+            return unit.form(plural_form).to_string();
         }
-        .replace("{}", if plural_form { "s" } else { "" })
+        // the local currency goes without its country, e.g. "dollars" in Canada
+        let local = matches!(
+            (currency, self.region),
+            (Currency::CAD, RegionFrench::CA) | (Currency::CHF, RegionFrench::CH)
+        );
+        let name = match currency {
+            Currency::CAD if local => "dollar{}",
+            Currency::CHF if local => "franc{}",
+            Currency::AED => "dirham{} des Émirats arabes unis",
+            Currency::BOB => "boliviano{}",
+            Currency::BOV => "mvdol{} bolivien{}",
+            Currency::BTC => "bitcoin{}",
+            Currency::CHE => "euro{} WIR",
+            Currency::CHF => "franc{} suisse{}",
+            Currency::AFN => "afghani{}",
+            Currency::ALL => "lek{}",
+            Currency::AMD => "dram{} arménien{}",
+            Currency::AOA => "kwanza{}",
+            Currency::ARS => "peso{} argentin{}",
+            Currency::AUD => "dollar{} australien{}",
+            Currency::AWG => "florin{} arubais",
+            Currency::AZN => "manat{} azerbaïdjanais",
+            Currency::B | Currency::PAB => "balboa{}",
+            Currency::BAM => "mark{} convertible{}",
+            Currency::BBD => "dollar{} barbadien{}",
+            Currency::BDT => "taka{}",
+            Currency::BGN => "lev|leva",
+            Currency::BHD => "dinar{} bahreïni{}",
+            Currency::BIF => "franc{} burundais",
+            Currency::BMD => "dollar{} bermudien{}",
+            Currency::BND => "dollar{} de Brunei",
+            Currency::BRL => "réal|réaux",
+            Currency::BSD => "dollar{} bahaméen{}",
+            Currency::BTN => "ngultrum{}",
+            Currency::BWP => "pula{}",
+            Currency::BYN => "rouble{} biélorusse{}",
+            Currency::BZD => "dollar{} bélizien{}",
+            Currency::C | Currency::NIO => "córdoba{}",
+            Currency::CAD => "dollar{} canadien{}",
+            Currency::CDF => "franc{} congolais",
+            Currency::CHW => "franc{} WIR",
+            Currency::CLF => "unité{} de compte chilienne{}",
+            Currency::CLP => "peso{} chilien{}",
+            Currency::CNY => "yuan{}",
+            Currency::COP => "peso{} colombien{}",
+            Currency::COU => "unité{} de valeur réelle",
+            Currency::CRC => "colón{}",
+            Currency::CUP => "peso{} cubain{}",
+            Currency::CVE => "escudo{} cap-verdien{}",
+            Currency::CZK => "couronne{} tchèque{}",
+            Currency::DINAR => "dinar{}",
+            Currency::DJF => "franc{} djiboutien{}",
+            Currency::DKK => "couronne{} danoise{}",
+            Currency::DOLLAR => "dollar{}",
+            Currency::DOP => "peso{} dominicain{}",
+            Currency::DZD => "dinar{} algérien{}",
+            Currency::EGP => "livre{} égyptienne{}",
+            Currency::ERN => "nakfa{}",
+            Currency::ETB => "birr{} éthiopien{}",
+            Currency::ETH => "ether{}",
+            Currency::EUR => "euro{}",
+            Currency::FJD => "dollar{} fidjien{}",
+            Currency::FKP => "livre{} des Falkland",
+            Currency::GBP => "livre{}",
+            Currency::GEL => "lari{}",
+            Currency::GHS => "cedi{}",
+            Currency::GIP => "livre{} de Gibraltar",
+            Currency::GMD => "dalasi{}",
+            Currency::GNF => "franc{} guinéen{}",
+            Currency::GTQ | Currency::Q => "quetzal{}",
+            Currency::GYD => "dollar{} guyanien{}",
+            Currency::HKD => "dollar{} de Hong Kong",
+            Currency::HNL => "lempira{}",
+            Currency::HTG => "gourde{}",
+            Currency::HUF => "forint{}",
+            Currency::IDR => "roupie{} indonésienne{}",
+            Currency::ILS => "shekel{}",
+            Currency::INR => "roupie{}",
+            Currency::IQD => "dinar{} irakien{}",
+            Currency::IRR => "rial{} iranien{}",
+            Currency::ISK => "couronne{} islandaise{}",
+            Currency::JMD => "dollar{} jamaïcain{}",
+            Currency::JOD => "dinar{} jordanien{}",
+            Currency::JPY => "yen{}",
+            Currency::KES => "shilling{} kényan{}",
+            Currency::KGS => "som{}",
+            Currency::KHR => "riel{}",
+            Currency::KMF => "franc{} comorien{}",
+            Currency::KPW => "won{} nord-coréen{}",
+            Currency::KRW => "won{}",
+            Currency::KWD => "dinar{} koweïtien{}",
+            Currency::KYD => "dollar{} des îles Caïmans",
+            Currency::KZT => "tenge{}",
+            Currency::LAK => "kip{}",
+            Currency::LBP => "livre{} libanaise{}",
+            Currency::LKR => "roupie{} srilankaise{}",
+            Currency::LRD => "dollar{} libérien{}",
+            Currency::LSL => "loti|maloti",
+            Currency::LYD => "dinar{} libyen{}",
+            Currency::MAD => "dirham{} marocain{}",
+            Currency::MDL => "leu moldave|lei moldaves",
+            Currency::MGA => "ariary",
+            Currency::MKD => "denar{} macédonien{}",
+            Currency::MMK => "kyat{}",
+            Currency::MNT => "tugrik{}",
+            Currency::MOP => "pataca{}",
+            Currency::MRU => "ouguiya{}",
+            Currency::MUR => "roupie{} mauricienne{}",
+            Currency::MVR => "rufiyaa",
+            Currency::MWK => "kwacha{} malawite{}",
+            Currency::MXN => "peso{} mexicain{}",
+            Currency::MXV => "unité{} d'investissement mexicaine{}",
+            Currency::MYR => "ringgit{}",
+            Currency::MZN => "metical{}",
+            Currency::NAD => "dollar{} namibien{}",
+            Currency::NGN => "naira{}",
+            Currency::NOK => "couronne{} norvégienne{}",
+            Currency::NPR => "roupie{} népalaise{}",
+            Currency::NZD => "dollar{} néo-zélandais",
+            Currency::OMR => "rial{} omanais",
+            Currency::PEN => "sol{}",
+            Currency::PESO => "peso{}",
+            Currency::PGK => "kina{}",
+            Currency::PHP => "peso{} philippin{}",
+            Currency::PKR => "roupie{} pakistanaise{}",
+            Currency::PLN => "złoty{}",
+            Currency::PYG => "guaraní{}",
+            Currency::QAR => "riyal{} qatarien{}",
+            Currency::RIYAL => "riyal{}",
+            Currency::RON => "leu roumain|lei roumains",
+            Currency::RSD => "dinar{} serbe{}",
+            Currency::RUB => "rouble{}",
+            Currency::RWF => "franc{} rwandais",
+            Currency::SAR => "riyal{} saoudien{}",
+            Currency::SBD => "dollar{} des îles Salomon",
+            Currency::SCR => "roupie{} seychelloise{}",
+            Currency::SDG => "livre{} soudanaise{}",
+            Currency::SEK => "couronne{} suédoise{}",
+            Currency::SGD => "dollar{} de Singapour",
+            Currency::SHP => "livre{} de Sainte-Hélène",
+            Currency::SLE => "leone{}",
+            Currency::SOS => "shilling{} somalien{}",
+            Currency::SRD => "dollar{} surinamais",
+            Currency::SSP => "livre{} sud-soudanaise{}",
+            Currency::STN => "dobra{}",
+            Currency::SVC => "colón{} salvadorien{}",
+            Currency::SYP => "livre{} syrienne{}",
+            Currency::SZL => "lilangeni|emalangeni",
+            Currency::THB => "baht{}",
+            Currency::TJS => "somoni{}",
+            Currency::TMT => "manat{} turkmène{}",
+            Currency::TND => "dinar{} tunisien{}",
+            Currency::TOP => "pa'anga",
+            Currency::TRY => "lire{}",
+            Currency::TTD => "dollar{} de Trinité-et-Tobago",
+            Currency::TWD => "dollar{} de Taïwan",
+            Currency::TZS => "shilling{} tanzanien{}",
+            Currency::UAH => "hryvnia{}",
+            Currency::UGX => "shilling{} ougandais",
+            Currency::USD => "dollar{} américain{}",
+            Currency::USN => "dollar{} américain{} (jour suivant)",
+            Currency::UYI => "unité{} indexée{} uruguayenne{}",
+            Currency::UYU => "peso{} uruguayen{}",
+            Currency::UYW => "unité{} prévisionnelle{}",
+            Currency::UZS => "sum{} ouzbek{}",
+            Currency::VED => "bolivar{} numérique{}",
+            Currency::VES => "bolivar{}",
+            Currency::VND => "dong{}",
+            Currency::VUV => "vatu{}",
+            Currency::WST => "tala{}",
+            Currency::XAF | Currency::XOF => "franc{} CFA",
+            Currency::XAG => "once{} troy d'argent",
+            Currency::XAU => "once{} troy d'or",
+            Currency::XBA => "unité{} composite{} européenne{}",
+            Currency::XBB => "unité{} monétaire{} européenne{}",
+            Currency::XBC => "unité de compte européenne 9|unités de compte européennes 9",
+            Currency::XBD => "unité de compte européenne 17|unités de compte européennes 17",
+            Currency::XCD => "dollar{} des Caraïbes orientales",
+            Currency::XCG => "florin{} caribéen{}",
+            Currency::XDR => "droit de tirage spécial|droits de tirage spéciaux",
+            Currency::XPD => "once{} troy de palladium",
+            Currency::XPF => "franc{} CFP",
+            Currency::XPT => "once{} troy de platine",
+            Currency::XSU => "sucre{}",
+            Currency::XTS => "unité{} de test",
+            Currency::XUA => "unité{} de compte de la BAD",
+            Currency::XXX => "unité{}",
+            Currency::YER => "rial{} yéménite{}",
+            Currency::ZAR => "rand{}",
+            Currency::ZMW => "kwacha{} zambien{}",
+            Currency::ZWG => "or du Zimbabwe|or du Zimbabwe",
+            Currency::Custom(_) => return currency.default_string(plural_form),
+        };
+        inflect(name, plural_form)
     }
 
     fn cents(&self, currency: Currency, plural_form: bool) -> String {
-        match currency {
-            Currency::UAH => String::from("kopeck{}"),
-            Currency::DOLLAR => String::from("centime{}"),
+        if let Some((_, subunit)) = currency.custom_nouns(Lang::French) {
+            return subunit.form(plural_form).to_string();
+        }
+        match (currency, self.region) {
+            (Currency::CAD, RegionFrench::CA) => String::from("cent{}"),
+            (Currency::CHF, _) if self.prefer_rappen => String::from("rappen"),
+            (Currency::BYN | Currency::RUB | Currency::UAH, _) => String::from("kopeck{}"),
+            (Currency::DKK | Currency::NOK | Currency::SEK, _) => String::from("øre"),
             _ => currency.default_subunit_string("centime{}", plural_form),
         }
         .replace("{}", if plural_form { "s" } else { "" })
     }
 
+    fn whole_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        Ok(count(
+            &self.currency_cardinal(num, currency, false)?,
+            &self.currencies(currency, num != BigFloat::from(1)),
+        ))
+    }
+
+    /// Cardinal agreeing with the unit, or the subunit, of a custom currency,
+    /// e.g. une livre
+    fn currency_cardinal(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+        subunit: bool,
+    ) -> Result<String, Num2Err> {
+        match currency.custom_nouns(Lang::French) {
+            Some((unit, cent)) => {
+                let feminine = if subunit { cent } else { unit }.is_feminine();
+                French::new(feminine, self.reformed, self.region)
+                    .with_tens(self.tens)
+                    .with_scale(self.scale)
+                    .int_to_cardinal(num)
+            }
+            None => self.int_to_cardinal(num),
+        }
+    }
+
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
         let mut thousands = Vec::new();
         let bf_1000 = BigFloat::from(1000);
@@ -190,14 +568,14 @@ impl French {
                 };
                 match units {
                     0 => {
-                        if tens == 8 && i != 1 && self.region != RegionFrench::CH {
+                        if tens == 8 && i != 1 && self.tens.eighty == Eighty::QuatreVingts {
                             words.push(String::from("quatre-vingts"))
                         } else {
                             words.push(String::from(self.get_tens(tens)))
                         }
                     }
-                    _ => match (tens, self.region) {
-                        (0, _) => {
+                    _ => match tens {
+                        0 => {
                             if i != 1 || units > 1 || hundreds > 0 {
                                 // if i != 0, i.e., "un", "un million" but "mille"
                                 // if units > 1, e.g. 2000 => "deux mille"
@@ -211,14 +589,14 @@ impl French {
                                 ));
                             }
                         }
-                        (1, _) => words.push(String::from(TEENS[units])),
-                        (7, RegionFrench::FR) => words.push(format!(
+                        1 => words.push(String::from(TEENS[units])),
+                        7 if !self.tens.septante => words.push(format!(
                             "{}{}{}",
                             self.get_tens(tens - 1),
                             et_string,
                             TEENS[units]
                         )),
-                        (8, RegionFrench::FR) | (8, RegionFrench::BE) => words.push(format!(
+                        8 if self.tens.eighty == Eighty::QuatreVingts => words.push(format!(
                             "{}-{}",
                             self.get_tens(tens),
                             if i == 0 && units == 1 && self.feminine {
@@ -227,8 +605,8 @@ impl French {
                                 UNITS[units - 1]
                             }
                         )),
-                        (9, RegionFrench::FR) => {
-                            words.push(format!("{}-{}", self.get_tens(tens - 1), TEENS[units]))
+                        9 if !self.tens.nonante => {
+                            words.push(format!("{}-{}", TENS[7], TEENS[units]))
                         }
                         _ => words.push(format!(
                             "{}{}{}",
@@ -245,15 +623,13 @@ impl French {
             }
 
             if i != 0 && triplet != &0 {
-                if i > MEGAS.len() {
-                    return Err(Num2Err::CannotConvert);
-                }
+                let mega = self.mega(i);
                 let plural_form = if hundreds == 0 && tens == 0 && units == 1 || i == 1 {
                     ""
                 } else {
                     "s"
                 };
-                words.push(format!("{}{}", MEGAS[i - 1], plural_form));
+                words.push(format!("{mega}{plural_form}"));
             }
         }
 
@@ -271,7 +647,7 @@ impl French {
 
         let mut ordinal_part = num.frac();
         if !ordinal_part.is_zero() {
-            words.push(String::from("point"));
+            words.push(String::from(if self.virgule { "virgule" } else { "point" }));
         }
         while !ordinal_part.is_zero() {
             let digit = (ordinal_part * BigFloat::from(10)).int();
@@ -283,6 +659,42 @@ impl French {
         }
         Ok(words.join(" "))
     }
+
+    fn fraction_denominator(
+        &self,
+        denominator: BigFloat,
+        plural_form: bool,
+    ) -> Result<String, Num2Err> {
+        let word = match denominator.to_u64() {
+            Some(2) => String::from("demi"),
+            Some(3) => String::from("tiers"),
+            Some(4) => String::from("quart"),
+            _ => {
+                // compound denominators are hyphenated and scale nouns lose their
+                // leading "un", e.g. un deux-millième, un millionième
+                let cardinal = self.to_cardinal(denominator)?;
+                let mut words: Vec<&str> = cardinal.split_whitespace().collect();
+                if words.len() > 1 && words[0] == "un" {
+                    words.remove(0);
+                }
+                let last = words.pop().unwrap_or_default();
+                let last = last.strip_suffix('s').unwrap_or(last);
+                let last = match last.strip_suffix('e').unwrap_or(last) {
+                    stem if stem.ends_with("cinq") => format!("{stem}u"),
+                    stem if stem.ends_with("neuf") => format!("{}v", &stem[..stem.len() - 1]),
+                    stem => String::from(stem),
+                };
+                words.push(&last);
+                format!("{}ième", words.join("-"))
+            }
+        };
+
+        if plural_form && !word.ends_with('s') {
+            Ok(format!("{word}s"))
+        } else {
+            Ok(word)
+        }
+    }
 }
 
 impl Language for French {
@@ -323,7 +735,7 @@ impl Language for French {
     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
         Ok(format!(
             "{}{}",
-            num.to_u128().unwrap(),
+            num.to_u128().ok_or(Num2Err::CannotConvert)?,
             if num == BigFloat::from(1) {
                 "er"
             } else {
@@ -341,34 +753,301 @@ impl Language for French {
     }
 
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let both = self.both_parts && currency.minor_units() > 0;
         if num.is_inf() {
             Ok(format!(
                 "{}une infinité de {}",
                 if num.is_negative() { "moins " } else { "" },
                 self.currencies(currency, true)
             ))
-        } else if num.frac().is_zero() {
-            let words = self.int_to_cardinal(num)?;
-            Ok(format!(
-                "{} {}",
-                words,
-                self.currencies(currency, num != BigFloat::from(1))
-            ))
+        } else if num.frac().is_zero() && !both {
+            self.whole_currency(num, currency)
         } else {
-            let integral_part = num.int();
-            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
-            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let (integral_part, cents_nb) = split_minor(num, currency);
+            let cents_words = self.currency_cardinal(cents_nb, currency, true)?;
             let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
-            let integral_word = self.to_currency(integral_part, currency)?;
+            let integral_word = self.whole_currency(integral_part, currency)?;
 
-            if cents_nb.is_zero() {
+            if cents_nb.is_zero() && !both {
                 Ok(integral_word)
-            } else if integral_part.is_zero() {
+            } else if integral_part.is_zero() && !both {
                 Ok(format!("{cents_words} {cents_suffix}"))
             } else {
-                Ok(format!("{integral_word} et {cents_words} {cents_suffix}"))
+                Ok(join_parts(
+                    &integral_word,
+                    self.conjunction.unwrap_or("et"),
+                    &format!("{cents_words} {cents_suffix}"),
+                ))
+            }
+        }
+    }
+
+    fn to_cheque(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+        cents: Option<ChequeCents>,
+        only: bool,
+    ) -> Result<String, Num2Err> {
+        let (whole, cents_nb) = split_minor(num, currency);
+        let mut words = match cents.unwrap_or(ChequeCents::Words) {
+            ChequeCents::Words => self.to_currency(num, currency)?,
+            ChequeCents::Digits => format!(
+                "{} et {} {}",
+                self.to_currency(whole, currency)?,
+                minor_digits(cents_nb, currency),
+                self.cents(currency, cents_nb > BigFloat::from(1))
+            ),
+            ChequeCents::Fraction => format!(
+                "{} et {}",
+                self.to_currency(whole, currency)?,
+                minor_fraction(cents_nb, currency)
+            ),
+        };
+        if only {
+            words.push_str(" seulement");
+        }
+        Ok(words)
+    }
+
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err> {
+        // fractions are masculine nouns, e.g. un quart
+        let lang = French::new(false, self.reformed, self.region)
+            .with_tens(self.tens)
+            .with_virgule(self.virgule)
+            .with_scale(self.scale);
+
+        let mut words = vec![];
+        let mut numerator = numerator;
+        if numerator.is_negative() {
+            words.push(String::from("moins"));
+            numerator = -numerator;
+        }
+
+        let whole = (numerator / denominator).int();
+        let rest = numerator % denominator;
+
+        if !whole.is_zero() {
+            words.push(lang.int_to_cardinal(whole)?);
+            if rest.is_zero() {
+                return Ok(words.join(" "));
+            }
+            words.push(String::from("et"));
+        }
+
+        let one = BigFloat::from(1);
+        if whole.is_zero() || rest != one || denominator != BigFloat::from(2) {
+            // case 2 1/2 => deux et demi
+            words.push(lang.int_to_cardinal(rest)?);
+        }
+        words.push(lang.fraction_denominator(denominator, rest > one)?);
+
+        Ok(words.join(" "))
+    }
+
+    fn to_compact(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+    ) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if approximate {
+            words.push(String::from("environ"));
+        }
+        if scale == 0 {
+            words.push(self.to_cardinal(leading)?);
+            return Ok(words.join(" "));
+        }
+        let mega = self.mega(scale);
+        // mille is invariable, e.g. "deux virgule cinq mille"
+        let noun = match scale {
+            1 => Noun::new(mega.clone(), mega.clone()),
+            _ => Noun::new(mega.clone(), format!("{mega}s")),
+        };
+
+        // e.g. "deux millions et demi"
+        let half = self.prefer_half && leading.frac().abs() == BigFloat::from(0.5);
+        let count = if half { leading.int() } else { leading };
+        words.push(match count.to_u64() {
+            // "mille", not "un mille"
+            Some(1) if scale == 1 => mega,
+            _ => self.to_counted(count, &noun)?,
+        });
+        if half {
+            words.push(String::from("et demi"));
+        }
+        Ok(words.join(" "))
+    }
+
+    fn to_compact_currency(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        let noun = self.currency_noun(currency, false)?;
+        if scale == 0 {
+            let prefix = if approximate { "environ " } else { "" };
+            return Ok(format!("{prefix}{}", self.to_counted(leading, &noun)?));
+        }
+        let words = self.to_compact(leading, scale, approximate)?;
+        let name = noun.form(true);
+        Ok(match scale {
+            // mille is an adjective, e.g. "deux mille euros"
+            1 => format!("{words} {name}"),
+            // million and above are nouns, e.g. "deux millions d'euros"
+            _ => format!("{words} {}", of(name)),
+        })
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} pour cent", self.to_cardinal(num)?))
+    }
+
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} pour mille", self.to_cardinal(num)?))
+    }
+
+    fn to_scientific(&self, mantissa: BigFloat, exponent: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} fois dix puissance {}",
+            self.to_cardinal(mantissa)?,
+            self.to_cardinal(exponent)?
+        ))
+    }
+
+    fn to_time(&self, hours: u8, minutes: u8, seconds: Option<u8>) -> Result<String, Num2Err> {
+        let mut words = vec![];
+
+        if self.prefer_24h {
+            words.push(self.time_unit(hours, "heure")?);
+        } else {
+            words.push(match hours {
+                0 => String::from("minuit"),
+                12 => String::from("midi"),
+                _ => self.time_unit(hours % 12, "heure")?,
+            });
+        }
+
+        match seconds.filter(|&s| s != 0) {
+            Some(seconds) => {
+                if minutes != 0 {
+                    words.push(self.time_unit(minutes, "minute")?);
+                }
+                words.push(String::from("et"));
+                words.push(self.time_unit(seconds, "seconde")?);
+            }
+            None if minutes != 0 => {
+                let lang = French::new(true, self.reformed, self.region).with_tens(self.tens);
+                words.push(lang.int_to_cardinal(BigFloat::from(minutes))?);
+            }
+            None => (),
+        }
+
+        if !self.prefer_24h {
+            match hours {
+                1..=11 => words.push(String::from("du matin")),
+                13..=17 => words.push(String::from("de l'après-midi")),
+                18..=23 => words.push(String::from("du soir")),
+                _ => (),
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_date(&self, year: BigFloat, month: u8, day: u8) -> Result<String, Num2Err> {
+        // days are cardinals, except for the first one, e.g. le premier mars
+        let lang = French::new(false, self.reformed, self.region)
+            .with_tens(self.tens)
+            .with_virgule(self.virgule)
+            .with_scale(self.scale);
+        let day = if day == 1 {
+            String::from("premier")
+        } else {
+            lang.int_to_cardinal(BigFloat::from(day))?
+        };
+
+        Ok(format!(
+            "le {day} {} {}",
+            MONTHS[month as usize - 1],
+            lang.to_year(year)?
+        ))
+    }
+
+    fn to_regnal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // only the first is an ordinal, e.g. François premier but Louis quatorze
+        if num == BigFloat::from(1) {
+            return Ok(String::from(if self.feminine {
+                "première"
+            } else {
+                "premier"
+            }));
+        }
+        French::new(false, self.reformed, self.region)
+            .with_tens(self.tens)
+            .with_scale(self.scale)
+            .int_to_cardinal(num)
+    }
+
+    fn to_counted(&self, num: BigFloat, noun: &Noun) -> Result<String, Num2Err> {
+        // nouns are plural from two onwards, e.g. un virgule cinq kilomètre
+        let lang = French::new(noun.is_feminine(), self.reformed, self.region)
+            .with_virgule(self.virgule)
+            .with_scale(self.scale);
+        Ok(count(
+            &lang.to_cardinal(num)?,
+            noun.form(num.abs() >= BigFloat::from(2)),
+        ))
+    }
+
+    fn currency_noun(&self, currency: Currency, subunit: bool) -> Result<Noun, Num2Err> {
+        if let Some((unit, cent)) = currency.custom_nouns(Lang::French) {
+            return Ok(if subunit { cent } else { unit });
+        }
+        let name = |plural_form| match subunit {
+            true => self.cents(currency, plural_form),
+            false => self.currencies(currency, plural_form),
+        };
+        Ok(Noun::new(name(false), name(true)))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.to_counted(num, &self.unit_noun(unit))
+    }
+
+    fn to_digits(&self, groups: &[String]) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        for group in groups {
+            for chunk in digit_chunks(group, self.digit_group) {
+                words.extend(read_digit_chunk(chunk, |n| self.int_to_cardinal(n))?);
             }
         }
+        Ok(words.join(" "))
+    }
+}
+
+/// Complement of a noun introduced by "de", elided before a vowel
+fn of(name: &str) -> String {
+    if name.starts_with(['a', 'e', 'i', 'o', 'u', 'y', 'é', 'è', 'ê', 'à', 'â']) {
+        format!("d'{name}")
+    } else {
+        format!("de {name}")
+    }
+}
+
+/// Cardinal followed by the counted noun, through "de" when the cardinal ends
+/// with a scale noun, e.g. "un million de pommes"
+fn count(cardinal: &str, name: &str) -> String {
+    let scale_noun = ["illion", "illions", "illiard", "illiards"]
+        .iter()
+        .any(|suffix| cardinal.ends_with(suffix));
+    if scale_noun {
+        format!("{cardinal} {}", of(name))
+    } else {
+        format!("{cardinal} {name}")
     }
 }
 
@@ -576,6 +1255,13 @@ mod tests {
                 .to_words(),
             Ok(String::from("73ème"))
         );
+        assert_eq!(
+            Num2Words::new(1e50)
+                .lang(Lang::French)
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
     }
 
     #[test]
@@ -594,6 +1280,14 @@ mod tests {
                 .to_words(),
             Ok(String::from("douze point cinq un"))
         );
+        assert_eq!(
+            Num2Words::new(12.51)
+                .lang(Lang::French)
+                .prefer("virgule")
+                .cardinal()
+                .to_words(),
+            Ok(String::from("douze virgule cinq un"))
+        );
     }
 
     #[test]
@@ -610,7 +1304,7 @@ mod tests {
                 .lang(Lang::French)
                 .currency(Currency::USD)
                 .to_words(),
-            Ok(String::from("quatre mille dollars américain"))
+            Ok(String::from("quatre mille dollars américains"))
         );
         assert_eq!(
             Num2Words::new(1.)
@@ -635,6 +1329,622 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fraction() {
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::French).fraction(4).to_words(),
+            Ok(String::from("trois quarts"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::French).fraction(8).to_words(),
+            Ok(String::from("cinq huitièmes"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::French).fraction(3).to_words(),
+            Ok(String::from("deux tiers"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::French).fraction(2).to_words(),
+            Ok(String::from("un demi"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::French).fraction(2).to_words(),
+            Ok(String::from("deux et demi"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::French).fraction(8).to_words(),
+            Ok(String::from("deux et cinq huitièmes"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .prefer("f")
+                .fraction(100)
+                .to_words(),
+            Ok(String::from("un centième"))
+        );
+        let fraction = |numerator: i64, denominator: i64| {
+            Num2Words::new(numerator)
+                .lang(Lang::French)
+                .fraction(denominator)
+                .to_words()
+        };
+        assert_eq!(fraction(1, 5), Ok(String::from("un cinquième")));
+        assert_eq!(fraction(2, 9), Ok(String::from("deux neuvièmes")));
+        assert_eq!(fraction(1, 21), Ok(String::from("un vingt-et-unième")));
+        assert_eq!(fraction(1, 80), Ok(String::from("un quatre-vingtième")));
+        assert_eq!(fraction(3, 200), Ok(String::from("trois deux-centièmes")));
+        assert_eq!(fraction(1, 1000), Ok(String::from("un millième")));
+        assert_eq!(fraction(1, 2000), Ok(String::from("un deux-millième")));
+        assert_eq!(fraction(1, 1_000_000), Ok(String::from("un millionième")));
+        assert_eq!(
+            fraction(3, 2_000_000),
+            Ok(String::from("trois deux-millionièmes"))
+        );
+        assert_eq!(
+            fraction(1, 1_000_000_000),
+            Ok(String::from("un milliardième"))
+        );
+    }
+
+    #[test]
+    fn test_scientific() {
+        assert_eq!(
+            Num2Words::parse("1.6e-19")
+                .unwrap()
+                .lang(Lang::French)
+                .prefer("virgule")
+                .scientific()
+                .to_words(),
+            Ok(String::from(
+                "un virgule six fois dix puissance moins dix-neuf"
+            ))
+        );
+        assert_eq!(
+            Num2Words::parse("6.022e23")
+                .unwrap()
+                .lang(Lang::French)
+                .scientific()
+                .to_words(),
+            Ok(String::from(
+                "six point zéro deux deux fois dix puissance vingt-trois"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        let short = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .prefer("short")
+                .to_words()
+        };
+        assert_eq!(short(1e9), Ok(String::from("un billion")));
+        assert_eq!(short(2e12), Ok(String::from("deux trillions")));
+        assert_eq!(
+            short(5_001_000_000.0),
+            Ok(String::from("cinq billions un million"))
+        );
+        assert_eq!(
+            Num2Words::new(2e12)
+                .lang(Lang::French)
+                .prefer("short")
+                .prefer("long")
+                .to_words(),
+            Ok(String::from("deux billions"))
+        );
+    }
+
+    #[test]
+    fn test_currency_names() {
+        let currency = |num: f64, currency: Currency| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .currency(currency)
+                .to_words()
+        };
+        assert_eq!(
+            currency(2.5, Currency::DKK),
+            Ok(String::from("deux couronnes danoises et cinquante øre"))
+        );
+        assert_eq!(
+            currency(3.0, Currency::RON),
+            Ok(String::from("trois lei roumains"))
+        );
+        assert_eq!(
+            currency(1000.0, Currency::XOF),
+            Ok(String::from("mille francs CFA"))
+        );
+        assert_eq!(
+            currency(3.0, Currency::XAU),
+            Ok(String::from("trois onces troy d'or"))
+        );
+        assert_eq!(
+            currency(2.0, Currency::XDR),
+            Ok(String::from("deux droits de tirage spéciaux"))
+        );
+
+        // every name is translated, apart from the single words borrowed
+        // as is, like euro
+        let french = super::French::new(false, false, super::RegionFrench::FR);
+        for &currency in Currency::all() {
+            for plural_form in [false, true] {
+                let english = currency.default_string(plural_form);
+                assert!(
+                    !english.contains(' ') || french.currencies(currency, plural_form) != english,
+                    "{:?} has no French name",
+                    currency
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_minor_units() {
+        let currency = |num: f64, currency: Currency| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .currency(currency)
+                .to_words()
+        };
+        assert_eq!(
+            currency(0.25, Currency::KWD),
+            Ok(String::from("deux cent cinquante fils"))
+        );
+        assert_eq!(currency(99.5, Currency::JPY), Ok(String::from("cent yens")));
+    }
+
+    #[test]
+    fn test_custom_currency() {
+        let livre = CustomCurrency::new("FR_LIV", 2)
+            .with_names(
+                Lang::French,
+                Noun::new("livre", "livres").with_gender(Gender::Feminine),
+                Noun::new("sou", "sous"),
+            )
+            .register();
+        let currency = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::French_BE)
+                .currency(livre)
+                .to_words()
+        };
+        assert_eq!(currency(1.0), Ok(String::from("une livre")));
+        assert_eq!(
+            currency(21.01),
+            Ok(String::from("vingt et une livres et un sou"))
+        );
+        assert_eq!(currency(0.5), Ok(String::from("cinquante sous")));
+    }
+
+    #[test]
+    fn test_compact_currency() {
+        let compact = |num: f64, currency: Currency| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .prefer("virgule")
+                .compact_currency(currency, 3)
+                .to_words()
+        };
+        assert_eq!(
+            compact(2_500_000.0, Currency::EUR),
+            Ok(String::from("deux virgule cinq millions d'euros"))
+        );
+        assert_eq!(
+            compact(3e9, Currency::DOLLAR),
+            Ok(String::from("trois milliards de dollars"))
+        );
+        assert_eq!(
+            compact(2_000.0, Currency::EUR),
+            Ok(String::from("deux mille euros"))
+        );
+        assert_eq!(compact(1.0, Currency::EUR), Ok(String::from("un euro")));
+    }
+
+    #[test]
+    fn test_currency_parts() {
+        let currency = |num: f64, pref: &str| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .prefer(pref)
+                .to_words()
+        };
+        assert_eq!(
+            currency(2.0, "both"),
+            Ok(String::from("deux euros et zéro centimes"))
+        );
+        assert_eq!(
+            currency(1.5, "conjunction:avec"),
+            Ok(String::from("un euro avec cinquante centimes"))
+        );
+    }
+
+    #[test]
+    fn test_cheque() {
+        let cheque = |num: f64, prefs: &[&str]| {
+            prefs
+                .iter()
+                .fold(
+                    Num2Words::new(num).lang(Lang::French).cheque(Currency::EUR),
+                    |n, p| n.prefer(*p),
+                )
+                .to_words()
+        };
+        assert_eq!(
+            cheque(42.01, &[]),
+            Ok(String::from("quarante-deux euros et un centime"))
+        );
+        assert_eq!(
+            cheque(42.01, &["capitalized"]),
+            Ok(String::from("Quarante-deux euros et un centime"))
+        );
+        assert_eq!(
+            cheque(42.1, &["numeric", "only"]),
+            Ok(String::from("quarante-deux euros et 10 centimes seulement"))
+        );
+        assert_eq!(
+            cheque(1.0, &["fraction"]),
+            Ok(String::from("un euro et 00/100"))
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let compact = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .prefer("virgule")
+                .compact(3)
+                .to_words()
+        };
+        assert_eq!(
+            compact(2_500_000.0),
+            Ok(String::from("deux virgule cinq millions"))
+        );
+        assert_eq!(compact(1_000_000.0), Ok(String::from("un million")));
+        assert_eq!(compact(1_000.0), Ok(String::from("mille")));
+        assert_eq!(
+            compact(3_000_000_000.0),
+            Ok(String::from("trois milliards"))
+        );
+        assert_eq!(
+            Num2Words::new(2_987_654_321u64)
+                .lang(Lang::French)
+                .compact(1)
+                .to_words(),
+            Ok(String::from("environ trois milliards"))
+        );
+        assert_eq!(
+            Num2Words::new(1_500_000)
+                .lang(Lang::French)
+                .prefer("half")
+                .compact(2)
+                .to_words(),
+            Ok(String::from("un million et demi"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::French)
+                .prefer("virgule")
+                .percent()
+                .to_words(),
+            Ok(String::from("douze virgule cinq pour cent"))
+        );
+        assert_eq!(
+            Num2Words::new(80).lang(Lang::French).percent().to_words(),
+            Ok(String::from("quatre-vingts pour cent"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::French).permille().to_words(),
+            Ok(String::from("trois pour mille"))
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        assert_eq!(
+            Num2Words::new(14)
+                .lang(Lang::French)
+                .time(5, None)
+                .to_words(),
+            Ok(String::from("quatorze heures cinq"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::French)
+                .time(1, None)
+                .to_words(),
+            Ok(String::from("vingt et une heures une"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::French)
+                .time(0, None)
+                .to_words(),
+            Ok(String::from("zéro heure"))
+        );
+        assert_eq!(
+            Num2Words::new(23)
+                .lang(Lang::French)
+                .time(59, Some(59))
+                .to_words(),
+            Ok(String::from(
+                "vingt-trois heures cinquante-neuf minutes et cinquante-neuf secondes"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(14)
+                .lang(Lang::French)
+                .prefer("12h")
+                .time(5, None)
+                .to_words(),
+            Ok(String::from("deux heures cinq de l'après-midi"))
+        );
+        assert_eq!(
+            Num2Words::new(12)
+                .lang(Lang::French)
+                .prefer("12h")
+                .time(30, None)
+                .to_words(),
+            Ok(String::from("midi trente"))
+        );
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(
+            Num2Words::new(1901)
+                .lang(Lang::French)
+                .date(3, 3)
+                .to_words(),
+            Ok(String::from("le trois mars mille neuf cent un"))
+        );
+        assert_eq!(
+            Num2Words::new(2024)
+                .lang(Lang::French)
+                .date(1, 1)
+                .to_words(),
+            Ok(String::from("le premier janvier deux mille vingt-quatre"))
+        );
+        assert_eq!(
+            Num2Words::new(1971)
+                .lang(Lang::French_BE)
+                .prefer("f")
+                .date(8, 21)
+                .to_words(),
+            Ok(String::from(
+                "le vingt et un août mille neuf cent septante et un"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(
+            Num2Words::parse_digits("01 42 68 71 80")
+                .unwrap()
+                .lang(Lang::French)
+                .to_words(),
+            Ok(String::from(
+                "zéro un quatre deux six huit sept un huit zéro"
+            ))
+        );
+        assert_eq!(
+            Num2Words::parse_digits("01 42 68 71 80")
+                .unwrap()
+                .lang(Lang::French)
+                .prefer("pairs")
+                .to_words(),
+            Ok(String::from(
+                "zéro un quarante-deux soixante-huit soixante et onze quatre-vingts"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_regnal() {
+        assert_eq!(
+            Num2Words::parse_regnal("Louis XIV")
+                .unwrap()
+                .lang(Lang::French)
+                .to_words(),
+            Ok(String::from("Louis quatorze"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .regnal("François")
+                .to_words(),
+            Ok(String::from("François premier"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .prefer("f")
+                .regnal("Élisabeth")
+                .to_words(),
+            Ok(String::from("Élisabeth première"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::French)
+                .prefer("f")
+                .regnal("Élisabeth")
+                .to_words(),
+            Ok(String::from("Élisabeth deux"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .prefer("capitalized")
+                .regnal("François")
+                .to_words(),
+            Ok(String::from("François Premier"))
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .unit(Unit::Kilogram)
+                .to_words(),
+            Ok(String::from("un kilogramme"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::French)
+                .unit(Unit::Tonne)
+                .to_words(),
+            Ok(String::from("vingt et une tonnes"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang(Lang::French)
+                .prefer("virgule")
+                .unit(Unit::Kilometre)
+                .to_words(),
+            Ok(String::from("un virgule cinq kilomètre"))
+        );
+        assert_eq!(
+            Num2Words::new(90)
+                .lang(Lang::French_CH)
+                .unit(Unit::KilometrePerHour)
+                .to_words(),
+            Ok(String::from("nonante kilomètres par heure"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::French)
+                .unit(Unit::Kilometre)
+                .to_words(),
+            Ok(String::from("un million de kilomètres"))
+        );
+        assert_eq!(
+            Num2Words::new(2e9)
+                .lang(Lang::French)
+                .unit(Unit::Litre)
+                .to_words(),
+            Ok(String::from("deux milliards de litres"))
+        );
+    }
+
+    #[test]
+    fn test_counted() {
+        let counted = |num: f64, noun: Noun| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .counted(noun)
+                .to_words()
+        };
+        let pomme = Noun::new("pomme", "pommes").with_gender(Gender::Feminine);
+        assert_eq!(
+            counted(1e6, pomme.clone()),
+            Ok(String::from("un million de pommes"))
+        );
+        assert_eq!(
+            counted(2e9, pomme.clone()),
+            Ok(String::from("deux milliards de pommes"))
+        );
+        assert_eq!(
+            counted(1_000_001.0, pomme),
+            Ok(String::from("un million une pommes"))
+        );
+        assert_eq!(
+            counted(1e6, Noun::new("euro", "euros")),
+            Ok(String::from("un million d'euros"))
+        );
+        assert_eq!(
+            Num2Words::new(2e9)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("deux milliards d'euros"))
+        );
+    }
+
+    #[test]
+    fn test_tens_preferences() {
+        let cardinal = |num: i64, lang: Lang, pref: &str| {
+            Num2Words::new(num).lang(lang).prefer(pref).to_words()
+        };
+        assert_eq!(
+            cardinal(80, Lang::French_CH, "octante"),
+            Ok(String::from("octante"))
+        );
+        assert_eq!(
+            cardinal(81, Lang::French_CH, "quatre-vingts"),
+            Ok(String::from("quatre-vingt-un"))
+        );
+        assert_eq!(
+            cardinal(80, Lang::French_CH, "quatre-vingts"),
+            Ok(String::from("quatre-vingts"))
+        );
+        assert_eq!(
+            cardinal(91, Lang::French, "nonante"),
+            Ok(String::from("nonante et un"))
+        );
+        assert_eq!(
+            cardinal(99, Lang::French, "huitante"),
+            Ok(String::from("quatre-vingt-dix-neuf"))
+        );
+        assert_eq!(
+            cardinal(77, Lang::French_BE, "soixante-dix"),
+            Ok(String::from("soixante-dix-sept"))
+        );
+        assert_eq!(
+            Num2Words::new(2080)
+                .lang(Lang::French)
+                .prefer("octante")
+                .year()
+                .to_words(),
+            Ok(String::from("deux mille octante"))
+        );
+    }
+
+    #[test]
+    fn test_regional_currencies() {
+        let currency = |num: f64, lang: Lang, currency: Currency| {
+            Num2Words::new(num).lang(lang).currency(currency).to_words()
+        };
+        assert_eq!(
+            currency(1.01, Lang::French_CA, Currency::CAD),
+            Ok(String::from("un dollar et un cent"))
+        );
+        assert_eq!(
+            currency(2.0, Lang::French, Currency::CAD),
+            Ok(String::from("deux dollars canadiens"))
+        );
+        assert_eq!(
+            currency(2.5, Lang::French_CH, Currency::CHF),
+            Ok(String::from("deux francs et cinquante centimes"))
+        );
+        assert_eq!(
+            currency(2.0, Lang::French, Currency::CHF),
+            Ok(String::from("deux francs suisses"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::French_CH)
+                .currency(Currency::CHF)
+                .prefer("rappen")
+                .to_words(),
+            Ok(String::from("vingt rappen"))
+        );
+        // Canada counts like France
+        assert_eq!(
+            Num2Words::new(92).lang(Lang::French_CA).to_words(),
+            Ok(String::from("quatre-vingt-douze"))
+        );
+    }
+
     #[test]
     fn test_year() {
         assert_eq!(
@@ -666,6 +1976,23 @@ mod tests {
             );
             un = "un ";
         }
+
+        // beyond the table, the names are systematic
+        for (num, words) in [
+            ("1e102", "un septendécillion"),
+            ("2e105", "deux septendécilliards"),
+            ("1e126", "un unvigintillion"),
+            ("3e165", "trois septemvigintilliards"),
+        ] {
+            assert_eq!(
+                Num2Words::parse(num)
+                    .unwrap()
+                    .lang(Lang::French)
+                    .cardinal()
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
     }
 
     #[test]
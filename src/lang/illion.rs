@@ -0,0 +1,111 @@
+// Systematic names of large numbers after John Conway and Allan Wechsler,
+// from https://en.wikipedia.org/wiki/Names_of_large_numbers
+
+const SMALL: [&str; 10] = [
+    "n", "m", "b", "tr", "quadr", "quint", "sext", "sept", "oct", "non",
+];
+
+const UNITS: [&str; 10] = [
+    "", "un", "duo", "tre", "quattuor", "quinqua", "se", "septe", "octo", "nove",
+];
+
+// prefixes with the markers changing the units before them
+const TENS: [(&str, &str); 10] = [
+    ("", ""),
+    ("deci", "N"),
+    ("viginti", "MS"),
+    ("triginta", "NS"),
+    ("quadraginta", "NS"),
+    ("quinquaginta", "NS"),
+    ("sexaginta", "N"),
+    ("septuaginta", "N"),
+    ("octoginta", "MX"),
+    ("nonaginta", ""),
+];
+
+const HUNDREDS: [(&str, &str); 10] = [
+    ("", ""),
+    ("centi", "NX"),
+    ("ducenti", "N"),
+    ("trecenti", "NS"),
+    ("quadringenti", "NS"),
+    ("quingenti", "NS"),
+    ("sescenti", "N"),
+    ("septingenti", "N"),
+    ("octingenti", "MX"),
+    ("nongenti", ""),
+];
+
+/// Latin stem of the n-th "-illion", to be followed by "illion", e.g.
+/// `vigint` for the twentieth and `unvigint` for the twenty-first
+pub(crate) fn illion_stem(n: usize) -> String {
+    if n < 1000 {
+        return stem_below_thousand(n);
+    }
+
+    // e.g. millinillion for the thousandth
+    let mut groups = vec![];
+    let mut n = n;
+    while n > 0 {
+        groups.push(stem_below_thousand(n % 1000));
+        n /= 1000;
+    }
+    groups.reverse();
+    groups.join("illi")
+}
+
+fn stem_below_thousand(n: usize) -> String {
+    if n < 10 {
+        return String::from(SMALL[n]);
+    }
+
+    let (tens, tens_marker) = TENS[n / 10 % 10];
+    let (hundreds, hundreds_marker) = HUNDREDS[n / 100];
+    let marker = if tens.is_empty() {
+        hundreds_marker
+    } else {
+        tens_marker
+    };
+    let units = match (n % 10, marker) {
+        (3, m) if m.contains('S') || m.contains('X') => "tres",
+        (6, m) if m.contains('S') => "ses",
+        (6, m) if m.contains('X') => "sex",
+        (7, m) if m.contains('M') => "septem",
+        (7, m) if m.contains('N') => "septen",
+        (9, m) if m.contains('M') => "novem",
+        (9, m) if m.contains('N') => "noven",
+        (units, _) => UNITS[units],
+    };
+
+    let mut stem = format!("{units}{tens}{hundreds}");
+    // the final vowel gives way to "illion", e.g. viginti to vigintillion
+    stem.pop();
+    stem
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_illion_stem() {
+        let illion = |n: usize| format!("{}illion", illion_stem(n));
+        assert_eq!(illion(1), "million");
+        assert_eq!(illion(9), "nonillion");
+        assert_eq!(illion(10), "decillion");
+        assert_eq!(illion(13), "tredecillion");
+        assert_eq!(illion(17), "septendecillion");
+        assert_eq!(illion(20), "vigintillion");
+        assert_eq!(illion(21), "unvigintillion");
+        assert_eq!(illion(23), "tresvigintillion");
+        assert_eq!(illion(26), "sesvigintillion");
+        assert_eq!(illion(27), "septemvigintillion");
+        assert_eq!(illion(54), "quattuorquinquagintillion");
+        assert_eq!(illion(88), "octooctogintillion");
+        assert_eq!(illion(100), "centillion");
+        assert_eq!(illion(106), "sexcentillion");
+        assert_eq!(illion(1000), "millinillion");
+        assert_eq!(illion(1003), "millitrillion");
+        assert_eq!(illion(1_000_000), "millinillinillion");
+    }
+}
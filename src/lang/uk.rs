@@ -1,4 +1,9 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::lang::{
+    digit_chunks, join_parts, minor_digits, minor_fraction, read_digit_chunk, split_minor,
+    ChequeCents, Scale,
+};
+use crate::noun::Noun;
+use crate::{num2words::Num2Err, Currency, Lang, Language, Unit};
 use num_bigfloat::BigFloat;
 use std::str::FromStr;
 
@@ -19,6 +24,15 @@ pub enum Declension {
     Locative,
 }
 
+const DECLENSIONS: [Declension; 6] = [
+    Declension::Nominative,
+    Declension::Genitive,
+    Declension::Dative,
+    Declension::Accusative,
+    Declension::Instrumental,
+    Declension::Locative,
+];
+
 impl Declension {
     fn index(&self) -> usize {
         use Declension::*;
@@ -51,13 +65,7 @@ impl FromStr for Declension {
     }
 }
 
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub enum Gender {
-    #[default]
-    Masculine,
-    Feminine,
-    Neuter,
-}
+pub use crate::noun::Gender;
 
 impl FromStr for Gender {
     type Err = ();
@@ -119,6 +127,12 @@ pub struct Ukrainian {
     gender: Gender,
     number: GrammaticalNumber,
     declension: Declension,
+    prefer_24h: bool,
+    digit_group: usize,
+    prefer_half: bool,
+    scale: Scale,
+    both_parts: bool,
+    conjunction: Option<&'static str>,
 }
 
 impl Ukrainian {
@@ -164,6 +178,18 @@ impl Ukrainian {
         self.agreement_with_units(tens as usize, units as usize)
     }
 
+    /// Writes the number followed by the noun form agreeing with it, as
+    /// given by `form` for the grammatical number and case of the noun
+    fn agree(&self, num: BigFloat, form: impl Fn(&Ukrainian) -> String) -> Result<String, Num2Err> {
+        // fractional numbers always take the genitive singular, e.g. "дві цілі пʼять десятих метра"
+        let target_lang = if num.frac().is_zero() || num.is_inf() {
+            self.agreement_with_num(num.abs())
+        } else {
+            self.singular().set_declension(Declension::Genitive)
+        };
+        Ok(format!("{} {}", self.to_cardinal(num)?, form(&target_lang)))
+    }
+
     fn agreement_with_units(&self, tens: usize, units: usize) -> Ukrainian {
         if units == 0 || units > 4 || tens == 1 {
             if self.declension == Declension::Nominative {
@@ -365,7 +391,7 @@ const NOUN_2ST_GROUP_HARD_DECLENSIONS: [[&str; 6]; 2] = [ //долар
 
 #[rustfmt::skip]
 const NOUN_2ST_GROUP_SOFT_DECLENSIONS: [[&str; 6]; 2] = [ //юань
-    [ "ь",  "я",  "ю", "я", "єм",  "і"  ],
+    [ "ь",  "я",  "ю", "я", "ем",  "і"  ],
     [ "і", "ів", "ям", "і", "ями", "ях" ],
 ];
 
@@ -381,6 +407,30 @@ const NOUN_1ST_GROUP_HARD_DECLENSIONS: [[&str; 6]; 2] = [ //єна
     [ "и", "",  "ам", "и", "ами", "ах" ],
 ];
 
+#[rustfmt::skip]
+const NOUN_2ST_GROUP_MIXED_DECLENSIONS: [[&str; 6]; 2] = [ //куруш
+    [ "",  "а",  "у",  "а", "ем",  "і"  ],
+    [ "і", "ів", "ам", "і", "ами", "ах" ],
+];
+
+#[rustfmt::skip]
+const NOUN_2ST_GROUP_J_DECLENSIONS: [[&str; 6]; 2] = [ //лей
+    [ "й", "я",  "ю",  "я", "єм",  "ї"  ],
+    [ "ї", "їв", "ям", "ї", "ями", "ях" ],
+];
+
+#[rustfmt::skip]
+const NOUN_1ST_GROUP_KA_DECLENSIONS: [[&str; 6]; 2] = [ //марка
+    [ "ка", "ки", "ці",  "ку", "кою",  "ці"  ],
+    [ "ки", "ок", "кам", "ки", "ками", "ках" ],
+];
+
+#[rustfmt::skip]
+const NOUN_2ND_GROUP_NEUTER_DECLENSIONS: [[&str; 6]; 2] = [ //право
+    [ "о", "а", "у",  "о", "ом",  "і"  ],
+    [ "а", "",  "ам", "а", "ами", "ах" ],
+];
+
 #[rustfmt::skip]
 const HRYVNIAS: [[&str; 6]; 2] = [
     [ "гривня", "гривні",  "гривні",  "гривню", "гривнею",  "гривні"  ],
@@ -399,264 +449,504 @@ const YEAR: [[&str; 6]; 2] = [
     [ "роки", "років", "рокам", "роки", "роками", "роках" ],
 ];
 
+#[rustfmt::skip]
+const PERCENTS: [[&str; 6]; 2] = [
+    [ "відсоток", "відсотка", "відсотку",  "відсоток", "відсотком",  "відсотку"  ],
+    [ "відсотки", "відсотків", "відсоткам", "відсотки", "відсотками", "відсотках" ],
+];
+
+const PER_MILLE: &str = "проміле";
+
+// in genitive, e.g. "третього березня"
+const MONTHS: [&str; 12] = [
+    "січня",
+    "лютого",
+    "березня",
+    "квітня",
+    "травня",
+    "червня",
+    "липня",
+    "серпня",
+    "вересня",
+    "жовтня",
+    "листопада",
+    "грудня",
+];
+
+// inanimate nouns of the second declension, hard group, e.g. метр
+#[rustfmt::skip]
+const NOUN_2ND_GROUP_HARD_INANIMATE_DECLENSIONS: [[&str; 6]; 2] = [
+    [ "",  "а",  "у",  "",  "ом",  "і"  ],
+    [ "и", "ів", "ам", "и", "ами", "ах" ],
+];
+
+#[rustfmt::skip]
+const MILE_DECLENSIONS: [[&str; 6]; 2] = [
+    [ "я", "і", "і",  "ю", "ею",  "і"  ],
+    [ "і", "ь", "ям", "і", "ями", "ях" ],
+];
+
+#[rustfmt::skip]
+const TONNE_DECLENSIONS: [[&str; 6]; 2] = [
+    [ "а", "и", "і",  "у", "ою",  "і"  ],
+    [ "и", "",  "ам", "и", "ами", "ах" ],
+];
+
+#[rustfmt::skip]
+const HOURS: [&str; 6] = ["година", "години", "годині", "годину", "годиною", "годині"];
+
+#[rustfmt::skip]
+const MINUTES: [[&str; 6]; 2] = [
+    [ "хвилина", "хвилини", "хвилині",  "хвилину", "хвилиною",  "хвилині"  ],
+    [ "хвилини", "хвилин",  "хвилинам", "хвилини", "хвилинами", "хвилинах" ],
+];
+
+#[rustfmt::skip]
+const SECONDS: [[&str; 6]; 2] = [
+    [ "секунда", "секунди", "секунді",  "секунду", "секундою",  "секунді"  ],
+    [ "секунди", "секунд",  "секундам", "секунди", "секундами", "секундах" ],
+];
+
+// Ukrainian name of a currency or of its subdivision
+#[derive(Clone, Copy)]
+enum CurrencyName {
+    // stem and endings of a declined noun, e.g. "долар"
+    Noun(&'static str, &'static [[&'static str; 6]; 2], Gender),
+    // noun that does not decline, e.g. "євро"
+    Indeclinable(&'static str, Gender),
+    // noun declined like an adjective, e.g. "злотий"
+    Adjective(&'static str, Gender),
+    // adjective followed by a name, e.g. "новий шекель"
+    Phrase(&'static str, &'static CurrencyName),
+    // name followed by a complement, e.g. "тройська унція золота"
+    Of(&'static CurrencyName, &'static str),
+}
+
+impl CurrencyName {
+    fn gender(&self) -> Gender {
+        match self {
+            CurrencyName::Noun(_, _, gender)
+            | CurrencyName::Indeclinable(_, gender)
+            | CurrencyName::Adjective(_, gender) => *gender,
+            CurrencyName::Phrase(_, name) | CurrencyName::Of(name, _) => name.gender(),
+        }
+    }
+}
+
+const fn masculine(stem: &'static str) -> CurrencyName {
+    CurrencyName::Noun(stem, &NOUN_2ST_GROUP_HARD_DECLENSIONS, Gender::Masculine)
+}
+
+const fn masculine_soft(stem: &'static str) -> CurrencyName {
+    CurrencyName::Noun(stem, &NOUN_2ST_GROUP_SOFT_DECLENSIONS, Gender::Masculine)
+}
+
+const fn feminine(stem: &'static str) -> CurrencyName {
+    CurrencyName::Noun(stem, &NOUN_1ST_GROUP_HARD_DECLENSIONS, Gender::Feminine)
+}
+
+const fn feminine_soft(stem: &'static str) -> CurrencyName {
+    CurrencyName::Noun(
+        stem,
+        &NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL,
+        Gender::Feminine,
+    )
+}
+
+const fn indeclinable(word: &'static str) -> CurrencyName {
+    CurrencyName::Indeclinable(word, Gender::Masculine)
+}
+
+const AGORA: CurrencyName = feminine("агор");
+const BAN: CurrencyName = masculine("бан");
+const BOLIVAR: CurrencyName = masculine("болівар");
+const CENT: CurrencyName = masculine("цент");
+const CENTAVO: CurrencyName = indeclinable("сентаво");
+const CENTESIMO: CurrencyName = indeclinable("сентесимо");
+const CENTIME: CurrencyName = masculine("сантим");
+const CENTIMO: CurrencyName = indeclinable("сентимо");
+const COLON: CurrencyName = masculine("колон");
+const CORDOBA: CurrencyName = feminine("кордоб");
+const DINAR: CurrencyName = masculine("динар");
+const DIRHAM: CurrencyName = masculine("дирхам");
+const DOLLAR: CurrencyName = masculine("долар");
+const EURO: CurrencyName = indeclinable("євро");
+const FILS: CurrencyName = masculine("філс");
+const FRANC: CurrencyName = masculine("франк");
+const KOPIYKA: CurrencyName =
+    CurrencyName::Noun("копій", &NOUN_1ST_GROUP_KA_DECLENSIONS, Gender::Feminine);
+const KRONA: CurrencyName = feminine("крон");
+const KWACHA: CurrencyName = CurrencyName::Indeclinable("квача", Gender::Feminine);
+const LEU: CurrencyName =
+    CurrencyName::Noun("ле", &NOUN_2ST_GROUP_J_DECLENSIONS, Gender::Masculine);
+const MANAT: CurrencyName = masculine("манат");
+const MARK: CurrencyName =
+    CurrencyName::Noun("мар", &NOUN_1ST_GROUP_KA_DECLENSIONS, Gender::Feminine);
+const ORE: CurrencyName = indeclinable("ере");
+const OUNCE: CurrencyName = CurrencyName::Phrase("тройськ", &feminine_soft("унці"));
+const PAISA: CurrencyName = masculine("пайс");
+const PENNY: CurrencyName = masculine("пенс");
+const PESO: CurrencyName = indeclinable("песо");
+const PIASTRE: CurrencyName = masculine("піастр");
+const POUND: CurrencyName = masculine("фунт");
+const QUETZAL: CurrencyName = masculine_soft("кетсал");
+const RIAL: CurrencyName = masculine("ріал");
+const RUBLE: CurrencyName = masculine_soft("рубл");
+const RUPEE: CurrencyName = feminine_soft("рупі");
+const SEN: CurrencyName = masculine("сен");
+const SHEKEL: CurrencyName = masculine_soft("шекел");
+const SHILLING: CurrencyName = masculine("шилінг");
+const UNIT: CurrencyName = CurrencyName::Noun("одиниц", &MILE_DECLENSIONS, Gender::Feminine);
+const ACCOUNT_UNIT: CurrencyName = CurrencyName::Phrase("розрахунков", &UNIT);
+const EUROPEAN_ACCOUNT_UNIT: CurrencyName = CurrencyName::Phrase("європейськ", &ACCOUNT_UNIT);
+const RIGHT: CurrencyName =
+    CurrencyName::Noun("прав", &NOUN_2ND_GROUP_NEUTER_DECLENSIONS, Gender::Neuter);
+const SPECIAL_RIGHT: CurrencyName = CurrencyName::Phrase("спеціальн", &RIGHT);
+const GOLD: CurrencyName =
+    CurrencyName::Noun("золот", &NOUN_2ND_GROUP_NEUTER_DECLENSIONS, Gender::Neuter);
+
+// Ukrainian names of the currencies and of their subdivisions, the same word
+// for several currencies like "долар" and "цент"
+#[rustfmt::skip]
+const CURRENCY_NAMES: &[(Currency, CurrencyName, CurrencyName)] = &[
+    (Currency::AED, DIRHAM, FILS),
+    (Currency::AFN, indeclinable("афгані"), masculine("пул")),
+    (Currency::ALL, masculine("лек"), CurrencyName::Noun("кіндар", &NOUN_1ST_GROUP_KA_DECLENSIONS, Gender::Feminine)),
+    (Currency::AMD, masculine("драм"), feminine("лум")),
+    (Currency::AOA, feminine("кванз"), CENTIMO),
+    (Currency::ARS, PESO, CENTAVO),
+    (Currency::AUD, DOLLAR, CENT),
+    (Currency::AWG, masculine("флорин"), CENT),
+    (Currency::AZN, MANAT, masculine("гяпік")),
+    (Currency::B, indeclinable("бальбоа"), CENTESIMO),
+    (Currency::BAM, CurrencyName::Phrase("конвертован", &MARK), masculine("фенінг")),
+    (Currency::BBD, DOLLAR, CENT),
+    (Currency::BDT, feminine("так"), feminine("пойш")),
+    (Currency::BGN, masculine("лев"), CurrencyName::Noun("стотин", &NOUN_1ST_GROUP_KA_DECLENSIONS, Gender::Feminine)),
+    (Currency::BHD, DINAR, FILS),
+    (Currency::BIF, FRANC, CENTIME),
+    (Currency::BMD, DOLLAR, CENT),
+    (Currency::BND, DOLLAR, CENT),
+    (Currency::BOB, indeclinable("болівіано"), CENTAVO),
+    (Currency::BOV, masculine("мвдол"), CENTAVO),
+    (Currency::BRL, masculine("реал"), CENTAVO),
+    (Currency::BSD, DOLLAR, CENT),
+    (Currency::BTC, masculine("біткоїн"), indeclinable("сатоші")),
+    (Currency::BTN, masculine("нгултрум"), masculine("четрум")),
+    (Currency::BWP, feminine("пул"), indeclinable("тхебе")),
+    (Currency::BYN, RUBLE, KOPIYKA),
+    (Currency::BZD, DOLLAR, CENT),
+    (Currency::C, CORDOBA, CENTAVO),
+    (Currency::CAD, DOLLAR, CENT),
+    (Currency::CDF, FRANC, CENTIME),
+    (Currency::CHE, CurrencyName::Of(&EURO, "WIR"), CENT),
+    (Currency::CHF, FRANC, CENTIME),
+    (Currency::CHW, CurrencyName::Of(&FRANC, "WIR"), CENTIME),
+    (Currency::CLF, ACCOUNT_UNIT, CENTAVO),
+    (Currency::CLP, PESO, CENTAVO),
+    (Currency::CNY, masculine_soft("юан"), masculine_soft("фен")),
+    (Currency::COP, PESO, CENTAVO),
+    (Currency::COU, CurrencyName::Of(&UNIT, "реальної вартості"), CENTAVO),
+    (Currency::CRC, COLON, indeclinable("сантимо")),
+    (Currency::CUP, PESO, CENTAVO),
+    (Currency::CVE, indeclinable("ескудо"), CENTAVO),
+    (Currency::CZK, KRONA, masculine("гелер")),
+    (Currency::DINAR, DINAR, FILS),
+    (Currency::DJF, FRANC, CENTIME),
+    (Currency::DKK, KRONA, ORE),
+    (Currency::DOLLAR, DOLLAR, CENT),
+    (Currency::DOP, PESO, CENTAVO),
+    (Currency::DZD, DINAR, FILS),
+    (Currency::EGP, POUND, PIASTRE),
+    (Currency::ERN, feminine("накф"), CENT),
+    (Currency::ETB, masculine("бир"), CENTIME),
+    (Currency::ETH, masculine("етер"), indeclinable("вей")),
+    (Currency::EUR, EURO, masculine("євроцент")),
+    (Currency::FJD, DOLLAR, CENT),
+    (Currency::FKP, POUND, PENNY),
+    (Currency::GBP, POUND, PENNY),
+    (Currency::GEL, indeclinable("ларі"), indeclinable("тетрі")),
+    (Currency::GHS, indeclinable("седі"), feminine("песев")),
+    (Currency::GIP, POUND, PENNY),
+    (Currency::GMD, indeclinable("даласі"), masculine("бутут")),
+    (Currency::GNF, FRANC, CENTIME),
+    (Currency::GTQ, QUETZAL, CENTAVO),
+    (Currency::GYD, DOLLAR, CENT),
+    (Currency::HKD, DOLLAR, CENT),
+    (Currency::HNL, feminine("лемпір"), CENTAVO),
+    (Currency::HTG, masculine("гурд"), CENTIME),
+    (Currency::HUF, masculine("форинт"), masculine("філлер")),
+    (Currency::IDR, RUPEE, SEN),
+    (Currency::ILS, CurrencyName::Phrase("нов", &SHEKEL), AGORA),
+    (Currency::INR, RUPEE, PAISA),
+    (Currency::IQD, DINAR, FILS),
+    (Currency::IRR, RIAL, DINAR),
+    (Currency::ISK, KRONA, indeclinable("ейре")),
+    (Currency::JMD, DOLLAR, CENT),
+    (Currency::JOD, DINAR, FILS),
+    (Currency::JPY, feminine("єн"), SEN),
+    (Currency::KES, SHILLING, CENT),
+    (Currency::KGS, masculine("сом"), masculine("тиїн")),
+    (Currency::KHR, masculine_soft("рієл"), SEN),
+    (Currency::KMF, FRANC, CENTIME),
+    (Currency::KPW, feminine("вон"), masculine("чон")),
+    (Currency::KRW, feminine("вон"), masculine("чон")),
+    (Currency::KWD, DINAR, FILS),
+    (Currency::KYD, DOLLAR, CENT),
+    (Currency::KZT, indeclinable("теньге"), masculine("тиїн")),
+    (Currency::LAK, masculine("кіп"), masculine("ат")),
+    (Currency::LBP, POUND, PIASTRE),
+    (Currency::LKR, RUPEE, CENT),
+    (Currency::LRD, DOLLAR, CENT),
+    (Currency::LSL, indeclinable("лоті"), indeclinable("сенте")),
+    (Currency::LYD, DINAR, DIRHAM),
+    (Currency::MAD, DIRHAM, CENTIME),
+    (Currency::MDL, LEU, BAN),
+    (Currency::MGA, indeclinable("аріарі"), CurrencyName::Indeclinable("іраймбіланжа", Gender::Feminine)),
+    (Currency::MKD, masculine("денар"), indeclinable("дені")),
+    (Currency::MMK, masculine("кʼят"), indeclinable("пʼя")),
+    (Currency::MNT, masculine("тугрик"), indeclinable("мунгу")),
+    (Currency::MOP, feminine("патак"), indeclinable("аво")),
+    (Currency::MRU, feminine_soft("угі"), masculine("хумс")),
+    (Currency::MUR, RUPEE, CENT),
+    (Currency::MVR, feminine_soft("руфі"), indeclinable("ларі")),
+    (Currency::MWK, KWACHA, indeclinable("тамбала")),
+    (Currency::MXN, PESO, CENTAVO),
+    (Currency::MXV, CurrencyName::Phrase("інвестиційн", &UNIT), CENTAVO),
+    (Currency::MYR, masculine("рингіт"), SEN),
+    (Currency::MZN, masculine("метикал"), CENTAVO),
+    (Currency::NAD, DOLLAR, CENT),
+    (Currency::NGN, feminine("найр"), indeclinable("кобо")),
+    (Currency::NIO, CORDOBA, CENTAVO),
+    (Currency::NOK, KRONA, ORE),
+    (Currency::NPR, RUPEE, PAISA),
+    (Currency::NZD, DOLLAR, CENT),
+    (Currency::OMR, RIAL, feminine("байз")),
+    (Currency::PAB, indeclinable("бальбоа"), CENTESIMO),
+    (Currency::PEN, masculine_soft("сол"), indeclinable("сентімо")),
+    (Currency::PESO, PESO, CENTAVO),
+    (Currency::PGK, CurrencyName::Indeclinable("кіна", Gender::Feminine), indeclinable("тоеа")),
+    (Currency::PHP, PESO, CENTAVO),
+    (Currency::PKR, RUPEE, PAISA),
+    (Currency::PLN, CurrencyName::Adjective("злот", Gender::Masculine), masculine("грош")),
+    (Currency::PYG, indeclinable("гуарані"), CENTIMO),
+    (Currency::Q, QUETZAL, CENTAVO),
+    (Currency::QAR, RIAL, FILS),
+    (Currency::RIYAL, RIAL, FILS),
+    (Currency::RON, LEU, BAN),
+    (Currency::RSD, DINAR, feminine("пар")),
+    (Currency::RUB, RUBLE, KOPIYKA),
+    (Currency::RWF, FRANC, CENTIME),
+    (Currency::SAR, RIAL, FILS),
+    (Currency::SBD, DOLLAR, CENT),
+    (Currency::SCR, RUPEE, CENT),
+    (Currency::SDG, POUND, PIASTRE),
+    (Currency::SEK, KRONA, ORE),
+    (Currency::SGD, DOLLAR, CENT),
+    (Currency::SHP, POUND, PENNY),
+    (Currency::SLE, indeclinable("леоне"), CENT),
+    (Currency::SOS, SHILLING, CENT),
+    (Currency::SRD, DOLLAR, CENT),
+    (Currency::SSP, POUND, PIASTRE),
+    (Currency::STN, feminine("добр"), CENTIMO),
+    (Currency::SVC, COLON, CENTAVO),
+    (Currency::SYP, POUND, PIASTRE),
+    (Currency::SZL, indeclinable("лілангені"), CENT),
+    (Currency::THB, masculine("бат"), masculine("сатанг")),
+    (Currency::TJS, indeclinable("сомоні"), masculine("дирам")),
+    (Currency::TMT, MANAT, indeclinable("теньге")),
+    (Currency::TND, DINAR, masculine("мілім")),
+    (Currency::TOP, CurrencyName::Indeclinable("паанга", Gender::Feminine), indeclinable("сеніті")),
+    (Currency::TRY, feminine("лір"), CurrencyName::Noun("куруш", &NOUN_2ST_GROUP_MIXED_DECLENSIONS, Gender::Masculine)),
+    (Currency::TTD, DOLLAR, CENT),
+    (Currency::TWD, DOLLAR, CENT),
+    (Currency::TZS, SHILLING, CENT),
+    (Currency::UAH, CurrencyName::Noun("", &HRYVNIAS, Gender::Feminine), CurrencyName::Noun("", &KOPIYKAS, Gender::Feminine)),
+    (Currency::UGX, SHILLING, CENT),
+    (Currency::USD, DOLLAR, CENT),
+    (Currency::USN, CurrencyName::Of(&DOLLAR, "наступного дня"), CENT),
+    (Currency::UYI, CurrencyName::Phrase("індексован", &UNIT), CENTESIMO),
+    (Currency::UYU, PESO, CENTAVO),
+    (Currency::UYW, CurrencyName::Phrase("пенсійн", &UNIT), CENTESIMO),
+    (Currency::UZS, masculine("сум"), masculine("тийин")),
+    (Currency::VED, CurrencyName::Phrase("цифров", &BOLIVAR), CENTIMO),
+    (Currency::VES, BOLIVAR, CENTIMO),
+    (Currency::VND, masculine("донг"), indeclinable("су")),
+    (Currency::VUV, indeclinable("вату"), CENT),
+    (Currency::WST, CurrencyName::Indeclinable("тала", Gender::Feminine), indeclinable("сене")),
+    (Currency::XAF, FRANC, CENTIME),
+    (Currency::XAG, CurrencyName::Of(&OUNCE, "срібла"), CENT),
+    (Currency::XAU, CurrencyName::Of(&OUNCE, "золота"), CENT),
+    (Currency::XBA, CurrencyName::Phrase("європейськ", &CurrencyName::Phrase("композитн", &UNIT)), CENT),
+    (Currency::XBB, CurrencyName::Phrase("європейськ", &CurrencyName::Phrase("монетарн", &UNIT)), CENT),
+    (Currency::XBC, CurrencyName::Of(&EUROPEAN_ACCOUNT_UNIT, "9"), CENT),
+    (Currency::XBD, CurrencyName::Of(&EUROPEAN_ACCOUNT_UNIT, "17"), CENT),
+    (Currency::XCD, DOLLAR, CENT),
+    (Currency::XCG, masculine("гульден"), CENT),
+    (Currency::XDR, CurrencyName::Of(&SPECIAL_RIGHT, "запозичення"), CENT),
+    (Currency::XOF, FRANC, CENTIME),
+    (Currency::XPD, CurrencyName::Of(&OUNCE, "паладію"), CENT),
+    (Currency::XPF, FRANC, CENTIME),
+    (Currency::XPT, CurrencyName::Of(&OUNCE, "платини"), CENT),
+    (Currency::XSU, indeclinable("сукре"), CENT),
+    (Currency::XTS, CurrencyName::Phrase("тестов", &UNIT), CENT),
+    (Currency::XUA, CurrencyName::Of(&ACCOUNT_UNIT, "АфБР"), CENT),
+    (Currency::XXX, UNIT, CENT),
+    (Currency::YER, RIAL, FILS),
+    (Currency::ZAR, masculine("ранд"), CENT),
+    (Currency::ZMW, KWACHA, indeclinable("нгве")),
+    (Currency::ZWG, CurrencyName::Phrase("зімбабвійськ", &GOLD), CENT),
+];
+
 impl Ukrainian {
     pub fn new(gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
         Self {
             gender,
             number,
             declension,
+            prefer_24h: true,
+            digit_group: 1,
+            prefer_half: false,
+            scale: Scale::Short,
+            both_parts: false,
+            conjunction: None,
         }
     }
 
-    fn currencies(&self, currency: Currency) -> String {
-        let number_idx = self.number.index();
-        let declension_idx = self.declension.index();
-        match currency {
-            Currency::AED => format!(
-                "дирхам{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::ARS
-            | Currency::CLP
-            | Currency::COP
-            | Currency::MXN
-            | Currency::PESO
-            | Currency::PHP
-            | Currency::UYU => String::from("песо"),
-            Currency::AUD
-            | Currency::CAD
-            | Currency::DOLLAR
-            | Currency::HKD
-            | Currency::NZD
-            | Currency::SGD
-            | Currency::TWD
-            | Currency::USD => format!(
-                "долар{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::BRL => format!(
-                "реал{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::CHF => format!(
-                "франк{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::CNY => format!(
-                "юан{}",
-                NOUN_2ST_GROUP_SOFT_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::CRC => format!(
-                "колон{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::DINAR | Currency::DZD | Currency::KWD => format!(
-                "динар{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::EUR => String::from("євро"),
-            Currency::GBP => format!(
-                "фунт{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::IDR | Currency::INR => format!(
-                "рупі{}",
-                NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL[number_idx][declension_idx]
-            ),
-            Currency::ILS => {
-                let adjective_flextion = if self.number == GrammaticalNumber::Plural {
-                    ADJECTIVE_HARD_FLEXIONS_PLURAL
-                } else {
-                    ADJECTIVE_HARD_FLEXIONS_SINGULAR[Gender::Masculine.index()]
-                }[self.declension.index()];
-                format!(
-                    "нов{} шекел{}",
-                    adjective_flextion, NOUN_2ST_GROUP_SOFT_DECLENSIONS[number_idx][declension_idx]
-                )
-            }
-            Currency::JPY => format!(
-                "єн{}",
-                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::KRW => format!(
-                "вон{}",
-                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::KZT => String::from("tenge"),
-            Currency::MYR => format!(
-                "рингіт{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::NOK => format!(
-                "крон{}",
-                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::PEN => format!(
-                "сол{}",
-                NOUN_2ST_GROUP_SOFT_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::PLN => {
-                let flextion = if self.number == GrammaticalNumber::Plural {
-                    ADJECTIVE_HARD_FLEXIONS_PLURAL
-                } else {
-                    ADJECTIVE_HARD_FLEXIONS_SINGULAR[Gender::Masculine.index()]
-                }[declension_idx];
-                format!("злот{flextion}")
-            }
-            Currency::QAR | Currency::RIYAL | Currency::SAR => format!(
-                "ріал{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::RUB => format!(
-                "рубл{}",
-                NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL[number_idx][declension_idx]
-            ),
-            Currency::THB => format!(
-                "бат{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::TRY => format!(
-                "куруш{}",
-                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::UAH => String::from(HRYVNIAS[number_idx][declension_idx]),
-            Currency::VND => format!(
-                "донг{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::ZAR => format!(
-                "ранд{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            _ => unimplemented!("Currency not implemented for Ukranian: {currency:?}"),
+    pub fn with_24h(self, prefer_24h: bool) -> Self {
+        Self { prefer_24h, ..self }
+    }
+
+    pub fn with_digit_group(self, digit_group: usize) -> Self {
+        Self {
+            digit_group,
+            ..self
         }
     }
 
-    fn currency_properties(&self, currency: Currency) -> Ukrainian {
-        match currency {
-            Currency::INR
-            | Currency::JPY
-            | Currency::KRW
-            | Currency::NOK
-            | Currency::TRY
-            | Currency::UAH => self.feminine(),
-            _ => self.masculine(),
+    pub fn with_half(self, prefer_half: bool) -> Self {
+        Self {
+            prefer_half,
+            ..self
         }
     }
 
-    fn currency_fraction(&self, currency: Currency) -> String {
-        let number_idx = self.number.index();
-        let declension_idx = self.declension.index();
-        match currency {
-            Currency::AED => format!(
-                "філс{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::ARS
-            | Currency::CLP
-            | Currency::COP
-            | Currency::MXN
-            | Currency::PESO
-            | Currency::PHP
-            | Currency::UYU
-            | Currency::BRL => String::from("сентаво"),
-            Currency::AUD
-            | Currency::CAD
-            | Currency::DOLLAR
-            | Currency::HKD
-            | Currency::NZD
-            | Currency::SGD
-            | Currency::TWD
-            | Currency::USD => format!(
-                "цент{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::CHF => format!(
-                "сантим{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::CNY => format!(
-                "фен{}",
-                NOUN_2ST_GROUP_SOFT_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::CRC => String::from("сантимо"),
-            Currency::DINAR | Currency::DZD | Currency::KWD => format!(
-                "філс{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::EUR => format!(
-                "євроцент{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::GBP => format!(
-                "пенс{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::IDR => format!(
-                "сен{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::INR => format!(
-                "пайс{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::ILS => format!(
-                "агор{}",
-                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::JPY => format!(
-                "сен{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::KRW => format!(
-                "чон{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::KZT => format!(
-                "тиїн{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::MYR => format!(
-                "сен{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::NOK => String::from("оре"),
-            Currency::PEN => String::from("сентімо"),
-            Currency::PLN => format!(
-                "грош{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::QAR | Currency::RIYAL | Currency::SAR => format!(
-                "філс{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::RUB => format!(
-                "копійк{}",
-                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::THB => format!(
-                "cатанг{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
-            ),
-            Currency::TRY => format!(
-                "лір{}",
-                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+    pub fn with_scale(self, scale: Scale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn with_currency_parts(self, both_parts: bool, conjunction: Option<&'static str>) -> Self {
+        Self {
+            both_parts,
+            conjunction,
+            ..self
+        }
+    }
+
+    /// Base of the name of the given power of a thousand, the long scale
+    /// alternating "-йон" and "-ярд" names, e.g. "більйон" for the fourth one
+    fn mega_base(&self, order: usize) -> Option<String> {
+        if self.scale == Scale::Short || order == 1 {
+            return MEGA_BASES.get(order - 1).map(|base| String::from(*base));
+        }
+        let base = match order / 2 {
+            2 => "більйон",
+            power => MEGA_BASES.get(power)?,
+        };
+        match order % 2 {
+            0 => Some(String::from(base)),
+            _ => Some(format!("{}ярд", base.strip_suffix("йон")?)),
+        }
+    }
+
+    fn unit_noun(&self, unit: Unit) -> Noun {
+        let (base, suffix) = match unit {
+            Unit::Kilometre => ("кілометр", ""),
+            Unit::Metre => ("метр", ""),
+            Unit::Centimetre => ("сантиметр", ""),
+            Unit::Millimetre => ("міліметр", ""),
+            Unit::Mile => ("мил", ""),
+            Unit::Foot => ("фут", ""),
+            Unit::Inch => ("дюйм", ""),
+            Unit::Tonne => ("тонн", ""),
+            Unit::Kilogram => ("кілограм", ""),
+            Unit::Gram => ("грам", ""),
+            Unit::Pound => ("фунт", ""),
+            Unit::Litre => ("літр", ""),
+            Unit::Millilitre => ("мілілітр", ""),
+            Unit::Celsius => ("градус", " Цельсія"),
+            Unit::Fahrenheit => ("градус", " Фаренгейта"),
+            Unit::KilometrePerHour => ("кілометр", " на годину"),
+            Unit::MilePerHour => ("мил", " на годину"),
+        };
+        let (flexions, gender) = match unit {
+            Unit::Mile | Unit::MilePerHour => (MILE_DECLENSIONS, Gender::Feminine),
+            Unit::Tonne => (TONNE_DECLENSIONS, Gender::Feminine),
+            _ => (NOUN_2ND_GROUP_HARD_INANIMATE_DECLENSIONS, Gender::Masculine),
+        };
+        let declensions = flexions.map(|row| row.map(|flexion| format!("{base}{flexion}{suffix}")));
+        Noun::new("", "")
+            .with_gender(gender)
+            .with_declensions(declensions)
+    }
+
+    // Cardinal followed by a feminine noun in agreement, e.g. "пʼять хвилин"
+    fn time_unit(&self, num: u8, noun: [[&str; 6]; 2]) -> Result<String, Num2Err> {
+        let num = BigFloat::from(num);
+        let unit_lang = self.feminine().agreement_with_num(num);
+        Ok(format!(
+            "{} {}",
+            self.feminine().int_to_cardinal(num)?,
+            noun[unit_lang.number.index()][unit_lang.declension.index()]
+        ))
+    }
+
+    /// Form of a noun in the grammatical number and case of the language
+    fn noun_form(&self, noun: &Noun) -> String {
+        match (&noun.declensions, &noun.many) {
+            (Some(declensions), _) => {
+                declensions[self.number.index()][self.declension.index()].clone()
+            }
+            (None, Some(many)) if self.is_plural() && self.declension == Declension::Genitive => {
+                many.clone()
+            }
+            // the genitive singular of feminine and neuter nouns is their nominative plural,
+            // e.g. "книжки", "вікна"
+            (None, _) if !self.is_plural() && self.declension == Declension::Genitive => {
+                noun.plural.clone()
+            }
+            _ => noun.form(self.is_plural()).to_string(),
+        }
+    }
+
+    /// Form of the name of a currency in the grammatical number and case of the language
+    fn currency_form(&self, name: &CurrencyName) -> String {
+        match name {
+            CurrencyName::Noun(stem, endings, _) => format!(
+                "{stem}{}",
+                endings[self.number.index()][self.declension.index()]
             ),
-            Currency::UAH => String::from(KOPIYKAS[number_idx][declension_idx]),
-            Currency::VND => String::from("су"),
-            Currency::ZAR => format!(
-                "цент{}",
-                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            CurrencyName::Indeclinable(word, _) => String::from(*word),
+            CurrencyName::Adjective(stem, gender) => {
+                format!("{stem}{}", self.adjective_flexion(*gender))
+            }
+            CurrencyName::Phrase(adjective, name) => format!(
+                "{adjective}{} {}",
+                self.adjective_flexion(name.gender()),
+                self.currency_form(name)
             ),
-            _ => unimplemented!("Currency fraction not implemented for Ukranian: {currency:?}"),
+            CurrencyName::Of(name, complement) => {
+                format!("{} {complement}", self.currency_form(name))
+            }
         }
     }
 
-    fn currency_fraction_properties(&self, currency: Currency) -> Ukrainian {
-        match currency {
-            Currency::ILS | Currency::TRY | Currency::RUB | Currency::UAH => self.feminine(),
-            _ => self.masculine(),
-        }
+    fn adjective_flexion(&self, gender: Gender) -> &'static str {
+        let flexions = match self.number {
+            GrammaticalNumber::Singular => ADJECTIVE_HARD_FLEXIONS_SINGULAR[gender.index()],
+            GrammaticalNumber::Plural => ADJECTIVE_HARD_FLEXIONS_PLURAL,
+        };
+        flexions[self.declension.index()]
     }
 
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
@@ -724,15 +1014,13 @@ impl Ukrainian {
             }
 
             if order != 0 && triplet != &0 {
-                if order > MEGA_BASES.len() {
-                    return Err(Num2Err::CannotConvert);
-                }
+                let mega_base = self.mega_base(order).ok_or(Num2Err::CannotConvert)?;
                 let mega_flexion = if order == 1 {
                     THOUSAND_FLEXIONS[properties.number.index()][properties.declension.index()]
                 } else {
                     MEGA_FLEXIONS[properties.number.index()][properties.declension.index()]
                 };
-                words.push(format!("{}{}", MEGA_BASES[order - 1], mega_flexion));
+                words.push(format!("{mega_base}{mega_flexion}"));
             }
         }
 
@@ -751,6 +1039,17 @@ impl Ukrainian {
             numerator *= BigFloat::from(10);
             denominator *= BigFloat::from(10);
         }
+        let numerator_properties = self.agreement_with_num(numerator);
+        Ok(format!(
+            "{} {} {}",
+            self.whole_to_cardinal(whole)?,
+            self.feminine().int_to_cardinal(numerator)?,
+            numerator_properties.feminine().to_ordinal(denominator)?,
+        ))
+    }
+
+    // Integral part of a fractional number, e.g. "дві цілі"
+    fn whole_to_cardinal(&self, whole: BigFloat) -> Result<String, Num2Err> {
         let whole_properties = self.agreement_with_num(whole);
         let whole_flexion = if whole_properties.number == GrammaticalNumber::Plural {
             ADJECTIVE_HARD_FLEXIONS_PLURAL
@@ -758,15 +1057,10 @@ impl Ukrainian {
             ADJECTIVE_HARD_FLEXIONS_SINGULAR[Gender::Feminine.index()]
         }[whole_properties.declension.index()];
 
-        let whole_lang = whole_properties.feminine();
-        let numerator_properties = self.agreement_with_num(numerator);
-        let numerator_lang = numerator_properties.feminine();
         Ok(format!(
-            "{} ціл{} {} {}",
-            whole_lang.int_to_cardinal(whole)?,
-            whole_flexion,
-            numerator_lang.int_to_cardinal(numerator)?,
-            numerator_lang.to_ordinal(denominator)?,
+            "{} ціл{}",
+            self.feminine().int_to_cardinal(whole)?,
+            whole_flexion
         ))
     }
 
@@ -834,7 +1128,10 @@ impl Language for Ukrainian {
             && triplets[last_non_empty] == 1
             && triplets[last_non_empty + 1..].iter().all(|&t| t == 0)
         {
-            return Ok(format!("{}н{flexion}", MEGA_BASES[last_non_empty - 1]));
+            let mega_base = self
+                .mega_base(last_non_empty)
+                .ok_or(Num2Err::CannotConvert)?;
+            return Ok(format!("{mega_base}н{flexion}"));
         }
 
         // iterate over thousands
@@ -870,7 +1167,8 @@ impl Language for Ukrainian {
                             _ => (),
                         }
                     }
-                    word.push_str(&format!("{}н{flexion}", MEGA_BASES[order - 1]));
+                    let mega_base = self.mega_base(order).ok_or(Num2Err::CannotConvert)?;
+                    word.push_str(&format!("{mega_base}н{flexion}"));
                     words.push(word);
                 } else if tens == 0 && units == 0 {
                     words.push(format!("{HUNDRED_BASE}{flexion}"));
@@ -931,12 +1229,13 @@ impl Language for Ukrainian {
             }
 
             if order != 0 && triplet != &0 {
+                let mega_base = self.mega_base(order).ok_or(Num2Err::CannotConvert)?;
                 let mega_flexion = if order == 1 {
                     THOUSAND_FLEXIONS[properties.number.index()][properties.declension.index()]
                 } else {
                     MEGA_FLEXIONS[properties.number.index()][properties.declension.index()]
                 };
-                words.push(format!("{}{}", MEGA_BASES[order - 1], mega_flexion));
+                words.push(format!("{mega_base}{mega_flexion}"));
             }
         }
 
@@ -944,8 +1243,9 @@ impl Language for Ukrainian {
     }
 
     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let value = num.to_u128().ok_or(Num2Err::CannotConvert)?;
         let flexion = self.ordinal_flexion_short(num);
-        Ok(format!("{}-{flexion}", num.to_u128().unwrap()))
+        Ok(format!("{value}-{flexion}"))
     }
 
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
@@ -972,42 +1272,291 @@ impl Language for Ukrainian {
     }
 
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let both = self.both_parts && currency.minor_units() > 0;
+        let (whole, fraction) = split_minor(num, currency);
+        let unit = self.currency_noun(currency, false)?;
+        let whole_words = || self.to_counted(whole, &unit);
+        let fraction_words = || self.to_counted(fraction, &self.currency_noun(currency, true)?);
         if num.is_inf() {
-            let currency_lang = self.currency_properties(currency);
-            let target_lang = currency_lang.agreement_with_num(num);
-            Ok(format!(
-                "{} {}",
-                currency_lang.to_cardinal(num)?,
-                target_lang.currencies(currency)
-            ))
+            self.to_counted(num, &unit)
+        } else if fraction.is_zero() && !both {
+            whole_words()
+        } else if whole.is_zero() && !both {
+            fraction_words()
         } else {
-            let whole = num.int();
-            let fraction = num.frac();
-            if fraction.is_zero() || num.is_inf() {
-                let currency_lang = self.currency_properties(currency);
-                let target_lang = currency_lang.agreement_with_num(whole);
-                Ok(format!(
-                    "{} {}",
-                    currency_lang.int_to_cardinal(whole)?,
-                    target_lang.currencies(currency)
-                ))
-            } else if whole.is_zero() {
-                let fraction = fraction * BigFloat::from(100).int();
-                let currency_lang = self.currency_fraction_properties(currency);
-                let target_lang = currency_lang.agreement_with_num(fraction);
-                Ok(format!(
-                    "{} {}",
-                    currency_lang.int_to_cardinal(fraction)?,
-                    target_lang.currency_fraction(currency)
-                ))
-            } else {
-                Ok(format!(
-                    "{} {}",
+            // the parts follow each other without any conjunction by default
+            Ok(join_parts(
+                &whole_words()?,
+                self.conjunction.unwrap_or(""),
+                &fraction_words()?,
+            ))
+        }
+    }
+
+    fn to_cheque(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+        cents: Option<ChequeCents>,
+        only: bool,
+    ) -> Result<String, Num2Err> {
+        // payment documents close the amount with its cents, then with "рівно" if preferred
+        let (whole, cents_nb) = split_minor(num, currency);
+        let mut words = match cents.unwrap_or(ChequeCents::Words) {
+            ChequeCents::Words => self.to_currency(num, currency)?,
+            ChequeCents::Digits => {
+                let noun = self.currency_noun(currency, true)?;
+                let cents_lang = Self {
+                    gender: noun.gender,
+                    ..*self
+                }
+                .agreement_with_num(cents_nb);
+                format!(
+                    "{} {} {}",
                     self.to_currency(whole, currency)?,
-                    self.to_currency(fraction, currency)?,
-                ))
+                    minor_digits(cents_nb, currency),
+                    cents_lang.noun_form(&noun)
+                )
+            }
+            ChequeCents::Fraction => format!(
+                "{} {}",
+                self.to_currency(whole, currency)?,
+                minor_fraction(cents_nb, currency)
+            ),
+        };
+        if only {
+            words.push_str(" рівно");
+        }
+        Ok(words)
+    }
+
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        let mut numerator = numerator;
+        if numerator.is_negative() {
+            words.push(String::from(MINUS));
+            numerator = -numerator;
+        }
+
+        let whole = (numerator / denominator).int();
+        let rest = numerator % denominator;
+
+        if !whole.is_zero() {
+            if rest.is_zero() {
+                words.push(self.int_to_cardinal(whole)?);
+                return Ok(words.join(" "));
+            }
+            words.push(self.whole_to_cardinal(whole)?);
+        }
+
+        // § 107: the denominator is in genitive plural after numerators other than one,
+        // e.g. "одна четверта" but "три четвертих"
+        let numerator_lang = self.feminine();
+        let tail = (rest % BigFloat::from(100)).to_u64().unwrap_or_default();
+        let denominator_lang = if tail % 10 == 1 && tail != 11 {
+            numerator_lang.singular()
+        } else if self.declension == Declension::Nominative {
+            numerator_lang.plural().set_declension(Declension::Genitive)
+        } else {
+            numerator_lang.plural()
+        };
+        words.push(numerator_lang.int_to_cardinal(rest)?);
+        words.push(denominator_lang.to_ordinal(denominator)?);
+
+        Ok(words.join(" "))
+    }
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let percent_lang = self.masculine();
+        // fractional numbers always take the genitive singular, e.g. "пʼять цілих дві десятих відсотка"
+        let noun = if num.frac().is_zero() || num.is_inf() {
+            let target_lang = percent_lang.agreement_with_num(num.abs());
+            PERCENTS[target_lang.number.index()][target_lang.declension.index()]
+        } else {
+            PERCENTS[GrammaticalNumber::Singular.index()][Declension::Genitive.index()]
+        };
+        Ok(format!("{} {noun}", percent_lang.to_cardinal(num)?))
+    }
+
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // "проміле" is indeclinable and neuter
+        let permille_lang = Self {
+            gender: Gender::Neuter,
+            ..*self
+        };
+        Ok(format!("{} {PER_MILLE}", permille_lang.to_cardinal(num)?))
+    }
+
+    fn to_compact(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+    ) -> Result<String, Num2Err> {
+        // "близько" governs the genitive, e.g. "близько трьох мільярдів"
+        let (prefix, lang) = match approximate {
+            true => ("близько ", self.set_declension(Declension::Genitive)),
+            false => ("", *self),
+        };
+        if scale == 0 {
+            return Ok(format!("{prefix}{}", lang.to_cardinal(leading)?));
+        }
+        let base = self.mega_base(scale).ok_or(Num2Err::CannotConvert)?;
+        let (flexions, gender) = match scale {
+            1 => (THOUSAND_FLEXIONS, Gender::Feminine),
+            _ => (MEGA_FLEXIONS, Gender::Masculine),
+        };
+        let noun = Noun::new("", "")
+            .with_declensions(flexions.map(|row| row.map(|flexion| format!("{base}{flexion}"))))
+            .with_gender(gender);
+
+        // e.g. "два з половиною мільйони"
+        if self.prefer_half && leading.frac().abs() == BigFloat::from(0.5) {
+            let mega = lang.to_counted(leading.int(), &noun)?;
+            let (count, mega) = mega.rsplit_once(' ').unwrap_or(("", &mega));
+            return Ok(format!("{prefix}{count} з половиною {mega}"));
+        }
+        Ok(format!("{prefix}{}", lang.to_counted(leading, &noun)?))
+    }
+
+    fn to_compact_currency(
+        &self,
+        leading: BigFloat,
+        scale: usize,
+        approximate: bool,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        let noun = self.currency_noun(currency, false)?;
+        if scale == 0 {
+            let (prefix, lang) = match approximate {
+                true => ("близько ", self.set_declension(Declension::Genitive)),
+                false => ("", *self),
+            };
+            return Ok(format!("{prefix}{}", lang.to_counted(leading, &noun)?));
+        }
+        // the currency after a scale word is in the genitive plural, e.g. "два мільйони доларів"
+        let name = self
+            .plural()
+            .set_declension(Declension::Genitive)
+            .noun_form(&noun);
+        Ok(format!(
+            "{} {name}",
+            self.to_compact(leading, scale, approximate)?
+        ))
+    }
+
+    fn to_scientific(&self, mantissa: BigFloat, exponent: BigFloat) -> Result<String, Num2Err> {
+        // e.g. "шість цілих двадцять дві тисячні помножити на десять у степені двадцять три"
+        Ok(format!(
+            "{} помножити на десять у степені {}",
+            self.to_cardinal(mantissa)?,
+            self.masculine()
+                .set_declension(Declension::Nominative)
+                .to_cardinal(exponent)?
+        ))
+    }
+
+    fn to_time(&self, hours: u8, minutes: u8, seconds: Option<u8>) -> Result<String, Num2Err> {
+        // hours are read as ordinals, e.g. "чотирнадцята година"
+        let clock_hours = match hours % 12 {
+            _ if self.prefer_24h => hours,
+            0 => 12,
+            h => h,
+        };
+        let hour_lang = self.feminine().singular();
+        let mut words = vec![
+            hour_lang.to_ordinal(BigFloat::from(clock_hours))?,
+            String::from(HOURS[self.declension.index()]),
+        ];
+
+        if minutes != 0 {
+            words.push(self.time_unit(minutes, MINUTES)?);
+        }
+        if let Some(seconds) = seconds.filter(|&s| s != 0) {
+            words.push(self.time_unit(seconds, SECONDS)?);
+        }
+
+        if !self.prefer_24h {
+            words.push(String::from(match hours {
+                0..=3 => "ночі",
+                4..=11 => "ранку",
+                12..=16 => "дня",
+                _ => "вечора",
+            }));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_date(&self, year: BigFloat, month: u8, day: u8) -> Result<String, Num2Err> {
+        // the day is a neuter ordinal, in genitive unless another declension is requested
+        let day_declension = match self.declension {
+            Declension::Nominative => Declension::Genitive,
+            declension => declension,
+        };
+        let day_lang = Self {
+            gender: Gender::Neuter,
+            number: GrammaticalNumber::Singular,
+            declension: day_declension,
+            ..*self
+        };
+        let year_lang = self.singular().set_declension(Declension::Genitive);
+        let year = year_lang.to_year(year)?;
+        // dates drop the leading "одна" of the millennium, e.g. "тисяча девʼятсот першого року"
+        let year = match year.strip_prefix("одна тисяча") {
+            Some(rest) => format!("тисяча{rest}"),
+            None => year,
+        };
+
+        Ok(format!(
+            "{} {} {year}",
+            day_lang.to_ordinal(BigFloat::from(day))?,
+            MONTHS[month as usize - 1],
+        ))
+    }
+
+    fn to_regnal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.singular().to_ordinal(num)
+    }
+
+    fn to_counted(&self, num: BigFloat, noun: &Noun) -> Result<String, Num2Err> {
+        let noun_lang = Self {
+            gender: noun.gender,
+            ..*self
+        };
+        noun_lang.agree(num, |target_lang| target_lang.noun_form(noun))
+    }
+
+    fn currency_noun(&self, currency: Currency, subunit: bool) -> Result<Noun, Num2Err> {
+        if let Some((unit, fraction)) = currency.custom_nouns(Lang::Ukrainian) {
+            return Ok(if subunit { fraction } else { unit });
+        }
+        let (_, unit, fraction) = CURRENCY_NAMES
+            .iter()
+            .find(|(named, _, _)| *named == currency)
+            .ok_or(Num2Err::UnsupportedCurrency)?;
+        let name = if subunit { fraction } else { unit };
+        let declensions = [GrammaticalNumber::Singular, GrammaticalNumber::Plural].map(|number| {
+            DECLENSIONS.map(|declension| {
+                Ukrainian::new(name.gender(), number, declension).currency_form(name)
+            })
+        });
+        Ok(Noun::new("", "")
+            .with_declensions(declensions)
+            .with_gender(name.gender()))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.to_counted(num, &self.unit_noun(unit))
+    }
+
+    fn to_digits(&self, groups: &[String]) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        for group in groups {
+            for chunk in digit_chunks(group, self.digit_group.max(1)) {
+                words.extend(read_digit_chunk(chunk, |n| self.int_to_cardinal(n))?);
             }
         }
+        Ok(words.join(" "))
     }
 }
 
@@ -1143,6 +1692,13 @@ mod tests {
                 .to_words(),
             Ok(String::from("321-а"))
         );
+        assert_eq!(
+            Num2Words::new(1e50)
+                .lang(Lang::Ukrainian)
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
     }
 
     #[test]
@@ -1151,13 +1707,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(0, 0),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Genitive
+                declension: Declension::Genitive,
+                ..Default::default()
             },
             "failed agreement: 0"
         );
@@ -1165,13 +1723,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(0, 1),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             },
             "failed agreement: 1"
         );
@@ -1179,13 +1739,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(8, 2),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             },
             "failed agreement: 82"
         );
@@ -1193,13 +1755,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Dative
+                declension: Declension::Dative,
+                ..Default::default()
             }
             .agreement_with_units(1, 1),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Dative
+                declension: Declension::Dative,
+                ..Default::default()
             },
             "failed agreement: 11"
         );
@@ -1207,13 +1771,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Instrumental
+                declension: Declension::Instrumental,
+                ..Default::default()
             }
             .agreement_with_units(5, 4),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Instrumental
+                declension: Declension::Instrumental,
+                ..Default::default()
             },
             "failed agreement: 54"
         );
@@ -1221,13 +1787,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(1, 8),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Genitive
+                declension: Declension::Genitive,
+                ..Default::default()
             },
             "failed agreement: 18"
         );
@@ -1235,13 +1803,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(0, 1),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             },
             "failed agreement: 1 feminine"
         );
@@ -1365,24 +1935,538 @@ mod tests {
     }
 
     #[test]
-    fn test_year() {
+    fn test_scientific() {
         assert_eq!(
-            Num2Words::new(1.1).lang(Lang::Ukrainian).year().to_words(),
-            Err(num2words::Num2Err::FloatingYear)
+            Num2Words::parse("6.022e23")
+                .unwrap()
+                .lang(Lang::Ukrainian)
+                .scientific()
+                .to_words(),
+            Ok(String::from(
+                "шість цілих двадцять дві тисячні помножити на десять у степені двадцять три"
+            ))
         );
         assert_eq!(
-            Num2Words::new(2023).lang(Lang::Ukrainian).year().to_words(),
-            Ok(String::from("дві тисячі двадцять третій рік"))
+            Num2Words::parse("1.6e-19")
+                .unwrap()
+                .lang(Lang::Ukrainian)
+                .scientific()
+                .to_words(),
+            Ok(String::from(
+                "одна ціла шість десятих помножити на десять у степені мінус девʼятнадцять"
+            ))
         );
         assert_eq!(
-            Num2Words::new(-67).lang(Lang::Ukrainian).year().to_words(),
-            Ok(String::from("шістдесят сьомий рік до н.е."))
+            Num2Words::new(1e100).lang(Lang::Ukrainian).to_words(),
+            Ok(String::from("один помножити на десять у степені сто"))
         );
     }
 
     #[test]
-    fn test_float() {
-        assert_eq!(
+    fn test_scale() {
+        let long = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .prefer("long")
+                .to_words()
+        };
+        assert_eq!(long(1e9), Ok(String::from("один мільярд")));
+        assert_eq!(long(2e12), Ok(String::from("два більйони")));
+        assert_eq!(long(5e15), Ok(String::from("пʼять більярдів")));
+        assert_eq!(long(1e18), Ok(String::from("один трильйон")));
+        assert_eq!(long(1e21), Ok(String::from("один трильярд")));
+        assert_eq!(
+            Num2Words::new(2e12)
+                .lang(Lang::Ukrainian)
+                .prefer("long")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("двохбільйонний"))
+        );
+    }
+
+    #[test]
+    fn test_minor_units() {
+        let currency = |num: f64, currency: Currency| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .currency(currency)
+                .to_words()
+        };
+        assert_eq!(
+            currency(0.005, Currency::KWD),
+            Ok(String::from("пʼять філсів"))
+        );
+        assert_eq!(currency(2.4, Currency::JPY), Ok(String::from("дві єни")));
+    }
+
+    #[test]
+    fn test_custom_currency() {
+        let karbovanets = CustomCurrency::new("UK_KRB", 2)
+            .with_names(
+                Lang::Ukrainian,
+                Noun::new("карбованець", "карбованці").with_many("карбованців"),
+                Noun::new("копійка", "копійки")
+                    .with_many("копійок")
+                    .with_gender(Gender::Feminine),
+            )
+            .register();
+        let currency = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .currency(karbovanets)
+                .to_words()
+        };
+        assert_eq!(currency(2.0), Ok(String::from("два карбованці")));
+        assert_eq!(
+            currency(5.21),
+            Ok(String::from("пʼять карбованців двадцять одна копійка"))
+        );
+        assert_eq!(currency(0.02), Ok(String::from("дві копійки")));
+    }
+
+    #[test]
+    fn test_currency_names() {
+        let currency = |num: f64, currency: Currency| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .currency(currency)
+                .to_words()
+        };
+        assert_eq!(currency(5.0, Currency::TRY), Ok(String::from("пʼять лір")));
+        assert_eq!(
+            currency(2.05, Currency::RUB),
+            Ok(String::from("два рублі пʼять копійок"))
+        );
+        assert_eq!(
+            currency(21.0, Currency::BAM),
+            Ok(String::from("двадцять одна конвертована марка"))
+        );
+        assert_eq!(
+            currency(2.0, Currency::XAU),
+            Ok(String::from("дві тройські унції золота"))
+        );
+        assert_eq!(
+            currency(5.02, Currency::MDL),
+            Ok(String::from("пʼять леїв два бани"))
+        );
+        assert_eq!(
+            currency(1.0, Currency::XDR),
+            Ok(String::from("одне спеціальне право запозичення"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Ukrainian)
+                .prefer("о")
+                .currency(Currency::KZT)
+                .to_words(),
+            Ok(String::from("трьома теньге"))
+        );
+    }
+
+    #[test]
+    fn test_crypto() {
+        let currency = |num: f64, pref: &str| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::BTC)
+                .prefer(pref)
+                .to_words()
+        };
+        assert_eq!(
+            currency(0.00012345, "minor"),
+            Ok(String::from("дванадцять тисяч триста сорок пʼять сатоші"))
+        );
+        assert_eq!(
+            currency(1.5, "decimal"),
+            Ok(String::from("одна ціла пʼять десятих біткоїна"))
+        );
+        assert_eq!(
+            currency(5.0, "decimal"),
+            Ok(String::from("пʼять біткоїнів"))
+        );
+    }
+
+    #[test]
+    fn test_compact_currency() {
+        let compact = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .compact_currency(Currency::UAH, 3)
+                .to_words()
+        };
+        assert_eq!(
+            compact(2_500_000.0),
+            Ok(String::from("дві цілі пʼять десятих мільйона гривень"))
+        );
+        assert_eq!(
+            compact(3_000_000.0),
+            Ok(String::from("три мільйони гривень"))
+        );
+        assert_eq!(compact(2.0), Ok(String::from("дві гривні")));
+    }
+
+    #[test]
+    fn test_currency_parts() {
+        let currency = |num: f64, pref: &str| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .prefer(pref)
+                .to_words()
+        };
+        assert_eq!(
+            currency(0.5, "both"),
+            Ok(String::from("нуль гривень пʼятдесят копійок"))
+        );
+        assert_eq!(
+            currency(1.5, "conjunction:та"),
+            Ok(String::from("одна гривня та пʼятдесят копійок"))
+        );
+    }
+
+    #[test]
+    fn test_cheque() {
+        let cheque = |num: f64, prefs: &[&str]| {
+            prefs
+                .iter()
+                .fold(
+                    Num2Words::new(num)
+                        .lang(Lang::Ukrainian)
+                        .cheque(Currency::UAH),
+                    |n, p| n.prefer(*p),
+                )
+                .to_words()
+        };
+        assert_eq!(
+            cheque(42.01, &[]),
+            Ok(String::from("сорок дві гривні одна копійка"))
+        );
+        assert_eq!(
+            cheque(42.05, &["numeric"]),
+            Ok(String::from("сорок дві гривні 05 копійок"))
+        );
+        assert_eq!(
+            cheque(21.0, &["fraction", "uppercase"]),
+            Ok(String::from("ДВАДЦЯТЬ ОДНА ГРИВНЯ 00/100"))
+        );
+        assert_eq!(
+            cheque(42.05, &["numeric", "only"]),
+            Ok(String::from("сорок дві гривні 05 копійок рівно"))
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let compact = |num: f64, significant: usize| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .compact(significant)
+                .to_words()
+        };
+        assert_eq!(
+            compact(2_500_000.0, 3),
+            Ok(String::from("дві цілі пʼять десятих мільйона"))
+        );
+        assert_eq!(compact(3e9, 3), Ok(String::from("три мільярди")));
+        assert_eq!(
+            compact(21_000.0, 3),
+            Ok(String::from("двадцять одна тисяча"))
+        );
+        assert_eq!(
+            compact(2_987_654_321.0, 1),
+            Ok(String::from("близько трьох мільярдів"))
+        );
+        assert_eq!(
+            Num2Words::new(2_500_000)
+                .lang(Lang::Ukrainian)
+                .prefer("half")
+                .compact(2)
+                .to_words(),
+            Ok(String::from("два з половиною мільйони"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Ukrainian).percent().to_words(),
+            Ok(String::from("один відсоток"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Ukrainian).percent().to_words(),
+            Ok(String::from("три відсотки"))
+        );
+        assert_eq!(
+            Num2Words::new(15)
+                .lang(Lang::Ukrainian)
+                .percent()
+                .to_words(),
+            Ok(String::from("пʼятнадцять відсотків"))
+        );
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::Ukrainian)
+                .percent()
+                .to_words(),
+            Ok(String::from("дванадцять цілих пʼять десятих відсотка"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Ukrainian)
+                .prefer("орудний")
+                .percent()
+                .to_words(),
+            Ok(String::from("пʼятьма відсотками"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .permille()
+                .to_words(),
+            Ok(String::from("два проміле"))
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        assert_eq!(
+            Num2Words::new(14)
+                .lang(Lang::Ukrainian)
+                .time(5, None)
+                .to_words(),
+            Ok(String::from("чотирнадцята година пʼять хвилин"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Ukrainian)
+                .time(1, Some(2))
+                .to_words(),
+            Ok(String::from(
+                "двадцять перша година одна хвилина дві секунди"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Ukrainian)
+                .time(0, None)
+                .to_words(),
+            Ok(String::from("нульова година"))
+        );
+        assert_eq!(
+            Num2Words::new(14)
+                .lang(Lang::Ukrainian)
+                .prefer("12h")
+                .time(30, None)
+                .to_words(),
+            Ok(String::from("друга година тридцять хвилин дня"))
+        );
+        assert_eq!(
+            Num2Words::new(9)
+                .lang(Lang::Ukrainian)
+                .prefer("орудний")
+                .time(5, None)
+                .to_words(),
+            Ok(String::from("девʼятою годиною пʼятьма хвилинами"))
+        );
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(
+            Num2Words::new(1901)
+                .lang(Lang::Ukrainian)
+                .date(3, 3)
+                .to_words(),
+            Ok(String::from(
+                "третього березня тисяча девʼятсот першого року"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(2024)
+                .lang(Lang::Ukrainian)
+                .date(8, 24)
+                .to_words(),
+            Ok(String::from(
+                "двадцять четвертого серпня дві тисячі двадцять четвертого року"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Ukrainian)
+                .prefer("давальний")
+                .date(1, 1)
+                .to_words(),
+            Ok(String::from("першому січня двохтисячного року"))
+        );
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(
+            Num2Words::parse_digits("044 123-45-67")
+                .unwrap()
+                .lang(Lang::Ukrainian)
+                .to_words(),
+            Ok(String::from(
+                "нуль чотири чотири один два три чотири пʼять шість сім"
+            ))
+        );
+        assert_eq!(
+            Num2Words::parse_digits("044 123-45-67")
+                .unwrap()
+                .lang(Lang::Ukrainian)
+                .prefer("pairs")
+                .to_words(),
+            Ok(String::from(
+                "нуль сорок чотири один двадцять три сорок пʼять шістдесят сім"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_regnal() {
+        assert_eq!(
+            Num2Words::parse_regnal("Людовик XIV")
+                .unwrap()
+                .lang(Lang::Ukrainian)
+                .to_words(),
+            Ok(String::from("Людовик чотирнадцятий"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .prefer("ж")
+                .regnal("Єлизавета")
+                .to_words(),
+            Ok(String::from("Єлизавета друга"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .prefer("ж")
+                .prefer("capitalized")
+                .regnal("Єлизавета")
+                .to_words(),
+            Ok(String::from("Єлизавета Друга"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Ukrainian)
+                .prefer("орудний")
+                .regnal("Петром")
+                .to_words(),
+            Ok(String::from("Петром першим"))
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        let unit = |n: f64, unit: Unit| Num2Words::new(n).lang(Lang::Ukrainian).unit(unit);
+        assert_eq!(
+            unit(2.0, Unit::Kilogram).to_words(),
+            Ok(String::from("два кілограми"))
+        );
+        assert_eq!(
+            unit(5.0, Unit::Kilogram).to_words(),
+            Ok(String::from("пʼять кілограмів"))
+        );
+        assert_eq!(
+            unit(21.0, Unit::Mile).to_words(),
+            Ok(String::from("двадцять одна миля"))
+        );
+        assert_eq!(
+            unit(11.0, Unit::Tonne).to_words(),
+            Ok(String::from("одинадцять тонн"))
+        );
+        assert_eq!(
+            unit(2.5, Unit::Metre).to_words(),
+            Ok(String::from("дві цілі пʼять десятих метра"))
+        );
+        assert_eq!(
+            unit(3.0, Unit::Celsius).to_words(),
+            Ok(String::from("три градуси Цельсія"))
+        );
+        assert_eq!(
+            unit(60.0, Unit::KilometrePerHour)
+                .prefer("орудний")
+                .to_words(),
+            Ok(String::from("шістдесятьма кілометрами на годину"))
+        );
+    }
+
+    #[test]
+    fn test_counted() {
+        let book = Noun::new("книжка", "книжки")
+            .with_many("книжок")
+            .with_gender(Gender::Feminine);
+        let counted = |n: i64| {
+            Num2Words::new(n)
+                .lang(Lang::Ukrainian)
+                .counted(book.clone())
+                .to_words()
+        };
+        assert_eq!(counted(21), Ok(String::from("двадцять одна книжка")));
+        assert_eq!(counted(22), Ok(String::from("двадцять дві книжки")));
+        assert_eq!(counted(25), Ok(String::from("двадцять пʼять книжок")));
+        assert_eq!(counted(12), Ok(String::from("дванадцять книжок")));
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang(Lang::Ukrainian)
+                .counted(book.clone())
+                .to_words(),
+            Ok(String::from("одна ціла пʼять десятих книжки"))
+        );
+
+        let window = Noun::new("вікно", "вікна").with_gender(Gender::Neuter);
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Ukrainian)
+                .counted(window)
+                .to_words(),
+            Ok(String::from("одне вікно"))
+        );
+
+        let book = book.with_declensions([
+            ["книжка", "книжки", "книжці", "книжку", "книжкою", "книжці"],
+            [
+                "книжки",
+                "книжок",
+                "книжкам",
+                "книжки",
+                "книжками",
+                "книжках",
+            ],
+        ]);
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Ukrainian)
+                .prefer("місцевий")
+                .counted(book)
+                .to_words(),
+            Ok(String::from("трьох книжках"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::Ukrainian).year().to_words(),
+            Err(num2words::Num2Err::FloatingYear)
+        );
+        assert_eq!(
+            Num2Words::new(2023).lang(Lang::Ukrainian).year().to_words(),
+            Ok(String::from("дві тисячі двадцять третій рік"))
+        );
+        assert_eq!(
+            Num2Words::new(-67).lang(Lang::Ukrainian).year().to_words(),
+            Ok(String::from("шістдесят сьомий рік до н.е."))
+        );
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(
             Num2Words::new(1.1)
                 .lang(Lang::Ukrainian)
                 .cardinal()
@@ -1420,6 +2504,75 @@ mod tests {
             .float_to_cardinal(BigFloat::from(973.0)),
             Ok(String::from("девʼятсот сімдесят три"))
         );
+        // the numerals keep the requested declension, only the fraction noun agrees with them
+        let float = |num: f64, preference: &str| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .prefer(preference)
+                .cardinal()
+                .to_words()
+        };
+        assert_eq!(float(1.5, ""), Ok(String::from("одна ціла пʼять десятих")));
+        assert_eq!(
+            float(2.25, ""),
+            Ok(String::from("дві цілі двадцять пʼять сотих"))
+        );
+        assert_eq!(
+            float(21.1, ""),
+            Ok(String::from("двадцять одна ціла одна десята"))
+        );
+        assert_eq!(
+            float(1.5, "родовий"),
+            Ok(String::from("одної цілої пʼяти десятих"))
+        );
+        assert_eq!(
+            float(2.25, "орудний"),
+            Ok(String::from("двома цілими двадцятьма пʼятьма сотими"))
+        );
+        assert_eq!(
+            float(21.1, "давальний"),
+            Ok(String::from("двадцяти одній цілій одній десятій"))
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Ukrainian)
+                .fraction(4)
+                .to_words(),
+            Ok(String::from("три четвертих"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Ukrainian)
+                .fraction(4)
+                .to_words(),
+            Ok(String::from("одна четверта"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .prefer("орудний")
+                .fraction(3)
+                .to_words(),
+            Ok(String::from("двома третіми"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Ukrainian)
+                .fraction(8)
+                .to_words(),
+            Ok(String::from("дві цілі пʼять восьмих"))
+        );
+        assert_eq!(
+            Num2Words::new(-1)
+                .lang(Lang::Ukrainian)
+                .fraction(100)
+                .to_words(),
+            Ok(String::from("мінус одна сота"))
+        );
     }
 
     #[test]
@@ -18,7 +18,9 @@
  * ```
  *
  * The builder `Num2Words` can take any of these methods: `lang`, `cardinal`,
- * `ordinal`, `ordinal_num`, `year`, and `currency`.
+ * `ordinal`, `ordinal_num`, `year`, `fraction`, `percent`, `permille`,
+ * `scientific`, `engineering`, `compact`, `time`, `date`, `roman`, `regnal`,
+ * `unit`, `counted`, `currency`, `cheque`, and `compact_currency`.
  *
  * ```rust
  * use num2words::*;
@@ -60,7 +62,7 @@
  *
  * You can also change the language via the CLI argument `--lang [locale]` and
  * provide a specific output type or a currency with the argument
- * `--to [cardinal|ordinal|ordinal_num|year|ISO 4217]`.
+ * `--to [cardinal|ordinal|ordinal_num|year|percent|permille|scientific|engineering|compact|digits|roman|regnal|unit symbol|ISO 4217|cheque:ISO 4217|compact:ISO 4217]`.
  *
  * For more information about the usage of `num2words` please refer to the docs
  * or via the following command:
@@ -74,14 +76,25 @@
  *
  * Here is a list of all of the supported languages:
  *
- * | Flag | Code              | Locale    | Language    | 42            |
- * | ---- | ----------------- | --------- | ----------- | ------------- |
- * | 🇺🇸🇬🇧 | `Lang::English`   | `en`      | English     | forty-two     |
- * | 🇫🇷🇨🇦 | `Lang::French`    | `fr`      | French      | quarante-deux |
- * | 🇧🇪🇨🇩 | `Lang::French_BE` | `fr_BE`   | French (BE) | quarante-deux |
- * | 🇨🇭   | `Lang::French_CH` | `fr_CH`   | French (CH) | quarante-deux |
- * | 🇪🇸   | `Lang::Spanish`   | `es`      | Spanish     | cuarenta y dos|
- * | 🇺🇦   | `Lang::Ukrainian` | `uk`      | Ukrainian   | сорок два     |
+ * | Flag | Code               | Locale  | Language     | 42             |
+ * | ---- | ------------------ | ------- | ------------ | -------------- |
+ * | 🇺🇸🇬🇧 | `Lang::English`    | `en`    | English      | forty-two      |
+ * | 🇺🇸   | `Lang::English_US` | `en_US` | English (US) | forty-two      |
+ * | 🇬🇧   | `Lang::English_GB` | `en_GB` | English (GB) | forty-two      |
+ * | 🇮🇳   | `Lang::English_IN` | `en_IN` | English (IN) | forty-two      |
+ * | 🇦🇺   | `Lang::English_AU` | `en_AU` | English (AU) | forty-two      |
+ * | 🇨🇦   | `Lang::English_CA` | `en_CA` | English (CA) | forty-two      |
+ * | 🇫🇷   | `Lang::French`     | `fr`    | French       | quarante-deux  |
+ * | 🇧🇪🇨🇩 | `Lang::French_BE`  | `fr_BE` | French (BE)  | quarante-deux  |
+ * | 🇨🇭   | `Lang::French_CH`  | `fr_CH` | French (CH)  | quarante-deux  |
+ * | 🇨🇦   | `Lang::French_CA`  | `fr_CA` | French (CA)  | quarante-deux  |
+ * | 🇪🇸   | `Lang::Spanish`    | `es`    | Spanish      | cuarenta y dos |
+ * | 🇪🇸   | `Lang::Spanish_ES` | `es_ES` | Spanish (ES) | cuarenta y dos |
+ * | 🇲🇽   | `Lang::Spanish_MX` | `es_MX` | Spanish (MX) | cuarenta y dos |
+ * | 🇦🇷   | `Lang::Spanish_AR` | `es_AR` | Spanish (AR) | cuarenta y dos |
+ * | 🇨🇴   | `Lang::Spanish_CO` | `es_CO` | Spanish (CO) | cuarenta y dos |
+ * | 🇨🇱   | `Lang::Spanish_CL` | `es_CL` | Spanish (CL) | cuarenta y dos |
+ * | 🇺🇦   | `Lang::Ukrainian`  | `uk`    | Ukrainian    | сорок два      |
  *
  * This list can be expanded! Contributions are welcomed.
  *
@@ -90,23 +103,53 @@
  * Here is a list of all of the supported outputs types (with the associated
  * command-line interface code):
  *
- * | Library method   | CLI argument  | Example output                         |
- * | ---------------- | ------------- | -------------------------------------- |
- * | `.cardinal()`    | `cardinal`    | forty-two (42)                         |
- * | `.ordinal()`     | `ordinal`     | forty-second (42)                      |
- * | `.ordinal_num()` | `ordinal_num` | 42nd (42)                              |
- * | `.year()`        | `year`        | nineteen oh-one (1901)                 |
- * | `.currency(cur)` | ISO 4217 code | forty-two dollars and one cent (42.01) |
+ * | Library method              | CLI argument  | Example output                           |
+ * | --------------------------- | ------------- | ---------------------------------------- |
+ * | `.cardinal()`               | `cardinal`    | forty-two (42)                           |
+ * | `.ordinal()`                | `ordinal`     | forty-second (42)                        |
+ * | `.ordinal_num()`            | `ordinal_num` | 42nd (42)                                |
+ * | `.year()`                   | `year`        | nineteen oh-one (1901)                   |
+ * | `.fraction(den)`            | —             | three quarters (3/4)                     |
+ * | `.percent()`                | `percent`     | forty-two percent (42)                   |
+ * | `.permille()`               | `permille`    | forty-two per mille (42)                 |
+ * | `.scientific()`             | `scientific`  | one times ten to the sixth (1e6)         |
+ * | `.engineering()`            | `engineering` | twelve times ten to the third (12e3)     |
+ * | `.compact(3)`               | `compact`     | two point five million (2500000)         |
+ * | `.compact_currency(cur, 3)` | `compact:USD` | two point five million dollars (2500000) |
+ * | `.time(m, s)`               | —             | two oh five pm (14:05)                   |
+ * | `.date(m, d)`               | —             | March third, two thousand (2000-03-03)   |
+ * | `parse_digits()`            | `digits`      | zero one four two (0142)                 |
+ * | `.roman()`                  | `roman`       | XLII (42)                                |
+ * | `.regnal(name)`             | `regnal`      | Henry the eighth (Henry VIII)            |
+ * | `.unit(unit)`               | unit symbol   | forty-two kilometres (42 km)             |
+ * | `.counted(noun)`            | —             | twenty-one apples (21)                   |
+ * | `.currency(cur)`            | ISO 4217 code | forty-two dollars and one cent (42.01)   |
+ * | `.cheque(cur)`              | `cheque:USD`  | forty-two and 01/100 dollars (42.01)     |
  *
  * ### Supported currencies
  *
- * Three-letter enum variants corresponds to the currency's ISO 4217 code, but
- * there are exceptions to accomodate generic terminologies: `DINAR`, `DOLLAR`,
- * `PESO` and `RIYAL`.
+ * Every active ISO 4217 currency is supported. Three-letter enum variants
+ * corresponds to the currency's ISO 4217 code, but there are exceptions to
+ * accomodate generic terminologies: `DINAR`, `DOLLAR`, `PESO` and `RIYAL`, and
+ * the symbols `B`, `C` and `Q`, along with the cryptocurrencies `BTC` and `ETH`,
+ * whose amounts can also be read in satoshis or in wei. Currencies can also be
+ * given by their numeric code, e.g. `978` for the euro. Other currencies, such as loyalty points or
+ * historical currencies, can be registered at runtime with their names in each
+ * language as a [`CustomCurrency`].
+ *
+ * Amounts of money such as `$42.01` or `42,01 €` are read with
+ * [`Num2Words::parse_amount`], or with [`Num2Words::parse_local_amount`] to
+ * follow the separators of a locale and its meaning of shared symbols like `$`.
  *
  * A summary of all of the supported currencies are available in the
  * documentation of [`Currency`].
  *
+ * ### Supported units
+ *
+ * Measurements can be read with their unit, e.g. `Unit::Kilometre` (`km`).
+ * A summary of all of the supported units are available in the
+ * documentation of [`Unit`].
+ *
  * ### About
  *
  * This library is widely inspired by [Savoir-faire Linux's Python
@@ -114,12 +157,18 @@
  */
 mod num2words;
 
+mod amount;
 mod currency;
 mod lang;
+mod noun;
 mod output;
+mod roman;
+mod unit;
 
 pub use crate::num2words::{Num2Err, Num2Words};
-pub use currency::Currency;
+pub use currency::{Currency, CustomCurrency, RegisteredCurrency};
 pub use lang::Lang;
 use lang::Language;
+pub use noun::{Gender, Noun};
 use output::Output;
+pub use unit::Unit;
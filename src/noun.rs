@@ -0,0 +1,104 @@
+/// Grammatical gender of a noun
+///
+/// French and Spanish only distinguish masculine and feminine nouns, neuter
+/// ones being read as masculine.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gender {
+    #[default]
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+/// Noun counted by a number, which the number and the noun agree with
+///
+/// Example:
+/// ```
+/// use num2words::{Gender, Lang, Noun, Num2Words};
+/// let apple = Noun::new("apple", "apples");
+/// assert_eq!(
+///     Num2Words::new(21).counted(apple).to_words(),
+///     Ok(String::from("twenty-one apples"))
+/// );
+///
+/// let casa = Noun::new("casa", "casas").with_gender(Gender::Feminine);
+/// assert_eq!(
+///     Num2Words::new(21).lang(Lang::Spanish).counted(casa).to_words(),
+///     Ok(String::from("veintiuna casas"))
+/// );
+///
+/// let book = Noun::new("книжка", "книжки")
+///     .with_many("книжок")
+///     .with_gender(Gender::Feminine);
+/// assert_eq!(
+///     Num2Words::new(25).lang(Lang::Ukrainian).counted(book).to_words(),
+///     Ok(String::from("двадцять пʼять книжок"))
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Noun {
+    pub(crate) singular: String,
+    pub(crate) plural: String,
+    pub(crate) many: Option<String>,
+    pub(crate) gender: Gender,
+    /// Forms by grammatical number then case (nominative, genitive, dative,
+    /// accusative, instrumental, locative), for languages with declensions
+    pub(crate) declensions: Option<[[String; 6]; 2]>,
+}
+
+impl Noun {
+    /// Creates a masculine noun from its singular and plural forms
+    pub fn new<T: Into<String>>(singular: T, plural: T) -> Self {
+        Self {
+            singular: singular.into(),
+            plural: plural.into(),
+            many: None,
+            gender: Gender::default(),
+            declensions: None,
+        }
+    }
+
+    /// Sets the grammatical gender of the noun
+    pub fn with_gender(self, gender: Gender) -> Self {
+        Self { gender, ..self }
+    }
+
+    /// Sets the form used after numbers ending in zero, in five and above or
+    /// in eleven to fourteen in Ukrainian (genitive plural), e.g. `книжок`
+    ///
+    /// Without it, the plural form is used.
+    pub fn with_many<T: Into<String>>(self, many: T) -> Self {
+        Self {
+            many: Some(many.into()),
+            ..self
+        }
+    }
+
+    /// Sets every declined form of the noun by grammatical number (singular,
+    /// plural) then case (nominative, genitive, dative, accusative,
+    /// instrumental, locative)
+    ///
+    /// The nominative forms replace the singular and plural forms.
+    pub fn with_declensions<T: Into<String>>(self, declensions: [[T; 6]; 2]) -> Self {
+        let declensions = declensions.map(|row| row.map(Into::into));
+        Self {
+            singular: declensions[0][0].clone(),
+            plural: declensions[1][0].clone(),
+            many: Some(declensions[1][1].clone()),
+            declensions: Some(declensions),
+            ..self
+        }
+    }
+
+    pub(crate) fn form(&self, plural: bool) -> &str {
+        if plural {
+            &self.plural
+        } else {
+            &self.singular
+        }
+    }
+
+    pub(crate) fn is_feminine(&self) -> bool {
+        self.gender == Gender::Feminine
+    }
+}
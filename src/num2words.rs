@@ -1,5 +1,8 @@
-use crate::{lang, Currency, Lang, Output};
-use num_bigfloat::BigFloat;
+use crate::lang::{minor_scale, ChequeCents, Language};
+use crate::roman::{self, RomanStyle};
+use crate::{amount, lang, Currency, Lang, Noun, Output, Unit};
+use num_bigfloat::{BigFloat, RoundingMode};
+use std::str::FromStr;
 
 /// Error type returned by the builder
 #[derive(Debug, PartialEq)]
@@ -7,13 +10,14 @@ pub enum Num2Err {
     /// General error, language cannot convert number
     ///
     /// It is likely that the language does not support the number because
-    /// it is too large.
+    /// it is too large. Cardinal numbers too large are read in scientific
+    /// notation instead.
     ///
     /// Example:
     /// ```
-    /// use num2words::{Num2Err, Num2Words};
+    /// use num2words::{Lang, Num2Err, Num2Words};
     /// assert_eq!(
-    ///     Num2Words::new(1e100).to_words(),
+    ///     Num2Words::new(1e100).lang(Lang::Ukrainian).ordinal().to_words(),
     ///     Err(Num2Err::CannotConvert)
     /// );
     /// ```
@@ -73,6 +77,91 @@ pub enum Num2Err {
     /// );
     /// ```
     InfiniteYear,
+    /// Request of a fraction with a zero denominator
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(3).fraction(0).to_words(),
+    ///     Err(Num2Err::ZeroDenominator)
+    /// );
+    /// ```
+    ZeroDenominator,
+    /// Request of an infinite fraction
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::parse("inf").unwrap().fraction(4).to_words(),
+    ///     Err(Num2Err::InfiniteFraction)
+    /// );
+    /// ```
+    InfiniteFraction,
+    /// Request of a time that is not on a 24-hour clock
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(24).time(5, None).to_words(),
+    ///     Err(Num2Err::InvalidTime)
+    /// );
+    /// ```
+    InvalidTime,
+    /// Request of a date that does not exist in the Gregorian calendar,
+    /// including any date of the year zero
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(1900).date(2, 29).to_words(),
+    ///     Err(Num2Err::InvalidDate)
+    /// );
+    /// ```
+    InvalidDate,
+    /// Request of a Roman numeral that cannot be written, i.e. zero, a
+    /// negative or floating number, or a number too large for the notation
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(4000).roman().to_words(),
+    ///     Err(Num2Err::InvalidRoman)
+    /// );
+    /// ```
+    InvalidRoman,
+    /// Request of a cheque for a negative or infinite amount
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(-42).cheque(Currency::DOLLAR).to_words(),
+    ///     Err(Num2Err::InvalidCheque)
+    /// );
+    /// ```
+    InvalidCheque,
+    /// Request of a currency whose names are missing in the language
+    ///
+    /// Every built-in currency has names in every language, so this error
+    /// is only returned when the data of a language is incomplete.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Lang, Num2Words};
+    /// for currency in Currency::all() {
+    ///     assert!(Num2Words::new(5)
+    ///         .lang(Lang::Ukrainian)
+    ///         .currency(*currency)
+    ///         .to_words()
+    ///         .is_ok());
+    /// }
+    /// ```
+    UnsupportedCurrency,
 }
 
 impl std::fmt::Display for Num2Err {
@@ -87,6 +176,13 @@ impl std::fmt::Display for Num2Err {
                 Num2Err::FloatingYear => "cannot treat float as year",
                 Num2Err::InfiniteOrdinal => "cannot treat infinity as ordinal",
                 Num2Err::InfiniteYear => "cannot treat infinity as year",
+                Num2Err::ZeroDenominator => "cannot treat zero as denominator",
+                Num2Err::InfiniteFraction => "cannot treat infinity as fraction",
+                Num2Err::InvalidTime => "cannot treat number as time",
+                Num2Err::InvalidDate => "cannot treat number as date",
+                Num2Err::InvalidRoman => "cannot treat number as roman numeral",
+                Num2Err::InvalidCheque => "cannot treat number as cheque amount",
+                Num2Err::UnsupportedCurrency => "cannot name currency in language",
             }
         )
     }
@@ -131,6 +227,9 @@ impl Num2Words {
 
     /// Creates a new builder from a string
     ///
+    /// Strings that are not numbers are read as Roman numerals, as with
+    /// [`Num2Words::parse_roman`].
+    ///
     /// Example:
     /// ```
     /// use num2words::Num2Words;
@@ -142,9 +241,16 @@ impl Num2Words {
     ///     Num2Words::parse("1e3").unwrap().to_words(),
     ///     Ok(String::from("one thousand"))
     /// );
+    /// assert_eq!(
+    ///     Num2Words::parse("XIV").unwrap().ordinal().to_words(),
+    ///     Ok(String::from("fourteenth"))
+    /// );
     /// ```
     pub fn parse(num: &str) -> Option<Self> {
-        let num = BigFloat::parse(num)?;
+        let num = match BigFloat::parse(num) {
+            Some(num) => num,
+            None => BigFloat::from(roman::parse_roman(num.trim())?),
+        };
         if num.is_nan() {
             return None;
         }
@@ -157,6 +263,225 @@ impl Num2Words {
         })
     }
 
+    /// Creates a new builder from a Roman numeral, either in uppercase or in
+    /// lowercase
+    ///
+    /// Only the standard notation up to MMMCMXCIX (3999) is read, in its
+    /// canonical spelling, e.g. `IV` but not `IIII`.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::parse_roman("XIV").unwrap().ordinal().to_words(),
+    ///     Ok(String::from("fourteenth"))
+    /// );
+    /// assert!(Num2Words::parse_roman("IIII").is_none());
+    /// ```
+    pub fn parse_roman(roman: &str) -> Option<Self> {
+        let num = roman::parse_roman(roman.trim())?;
+        Some(Self::new(num))
+    }
+
+    /// Creates a new builder from a clock time
+    ///
+    /// Accepts `hh:mm` or `hh:mm:ss`, optionally followed by `am` or `pm`.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::parse_time("14:05").unwrap().to_words(),
+    ///     Ok(String::from("two oh five pm"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_time("9:30 am").unwrap().to_words(),
+    ///     Ok(String::from("nine thirty am"))
+    /// );
+    /// ```
+    pub fn parse_time(time: &str) -> Option<Self> {
+        let time = time.trim().to_lowercase();
+        let (clock, meridiem) = match time.strip_suffix("am") {
+            Some(clock) => (clock, Some(false)),
+            None => match time.strip_suffix("pm") {
+                Some(clock) => (clock, Some(true)),
+                None => (time.as_str(), None),
+            },
+        };
+
+        let mut parts = clock.trim_end().split(':');
+        let mut hours: u8 = parts.next()?.parse().ok()?;
+        let minutes: u8 = parts.next()?.parse().ok()?;
+        let seconds = match parts.next() {
+            Some(s) => Some(s.parse().ok()?),
+            None => None,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+
+        if let Some(pm) = meridiem {
+            if !(1..=12).contains(&hours) {
+                return None;
+            }
+            hours = hours % 12 + if pm { 12 } else { 0 };
+        }
+
+        Some(Self::new(hours).time(minutes, seconds))
+    }
+
+    /// Creates a new builder reading a sequence of digits one by one, e.g., a
+    /// phone number or a code
+    ///
+    /// Digits can be grouped with spaces, dashes, dots, slashes or brackets,
+    /// each group being read on its own.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::parse_digits("555-0142").unwrap().prefer("oh").to_words(),
+    ///     Ok(String::from("five five five oh one four two"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_digits("555-0142").unwrap().prefer("pairs").to_words(),
+    ///     Ok(String::from("five fifty-five zero one forty-two"))
+    /// );
+    /// ```
+    pub fn parse_digits(digits: &str) -> Option<Self> {
+        let is_separator = |c: char| [' ', '-', '.', '/', '(', ')'].contains(&c);
+        if !digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || is_separator(c))
+            || !digits.chars().any(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let groups = digits
+            .split(is_separator)
+            .filter(|group| !group.is_empty())
+            .map(String::from)
+            .collect();
+        Some(Self {
+            num: BigFloat::from(0),
+            lang: Lang::English,
+            output: Output::Digits(groups),
+            currency: Currency::DOLLAR,
+            preferences: vec![],
+        })
+    }
+
+    /// Creates a new builder from a name followed by its regnal number,
+    /// either in Roman or in Arabic numerals
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::parse_regnal("Henry VIII").unwrap().to_words(),
+    ///     Ok(String::from("Henry the eighth"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_regnal("Louis XIV").unwrap().lang(Lang::French).to_words(),
+    ///     Ok(String::from("Louis quatorze"))
+    /// );
+    /// ```
+    pub fn parse_regnal(title: &str) -> Option<Self> {
+        let (name, num) = title.trim().rsplit_once(char::is_whitespace)?;
+        let num = match roman::parse_roman(num) {
+            Some(num) => BigFloat::from(num),
+            None => BigFloat::parse(num).filter(|num| !num.is_nan())?,
+        };
+        if name.trim().is_empty() {
+            return None;
+        }
+        Some(Self::new(num).regnal(name.trim()))
+    }
+
+    /// Creates a new builder from a measurement, i.e. a number followed by
+    /// the symbol of its unit
+    ///
+    /// For all of the available units, see [`Unit`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::parse_measurement("42.5 km").unwrap().to_words(),
+    ///     Ok(String::from("forty-two point five kilometres"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_measurement("1kg").unwrap().lang(Lang::French).to_words(),
+    ///     Ok(String::from("un kilogramme"))
+    /// );
+    /// ```
+    pub fn parse_measurement(measurement: &str) -> Option<Self> {
+        let measurement = measurement.trim();
+        let split = measurement
+            .find(|c: char| !(c.is_ascii_digit() || ['.', '-', '+'].contains(&c)))
+            .unwrap_or(measurement.len());
+        let (num, unit) = measurement.split_at(split);
+        let unit = Unit::from_str(unit.trim()).ok()?;
+        Some(Self::parse(num)?.unit(unit))
+    }
+
+    /// Creates a new builder from an amount of money, i.e. a number preceded
+    /// or followed by a currency symbol or ISO 4217 code
+    ///
+    /// A lone separator followed by three digits, as in `£1,250`, is read as
+    /// a grouping separator, and a `$` sign as the generic
+    /// [`Currency::DOLLAR`]. Use [`Num2Words::parse_local_amount`] to follow
+    /// the conventions of a given locale instead.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::parse_amount("$42.01").unwrap().to_words(),
+    ///     Ok(String::from("forty-two dollars and one cent"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_amount("42,01 €").unwrap().lang(Lang::French).to_words(),
+    ///     Ok(String::from("quarante-deux euros et un centime"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_amount("£1,250").unwrap().to_words(),
+    ///     Ok(String::from("one thousand two hundred and fifty pounds"))
+    /// );
+    /// ```
+    pub fn parse_amount(amount: &str) -> Option<Self> {
+        Self::parse_local_amount(amount, "")
+    }
+
+    /// Creates a new builder from an amount of money written in the
+    /// conventions of a locale, e.g. `en_US` or `es_MX`
+    ///
+    /// The language of the locale decides whether `1,250` is one thousand
+    /// two hundred and fifty or one and a quarter, while its region decides
+    /// which currency a shared symbol like `$`, `£`, `¥` or `kr` stands for.
+    /// The language of the output is left untouched.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Currency};
+    /// assert_eq!(
+    ///     Num2Words::parse_local_amount("$5", "en_CA").unwrap().to_words(),
+    ///     Ok(String::from("five canadian dollars"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_local_amount("1.250 €", "es_ES").unwrap().to_words(),
+    ///     Ok(String::from("one thousand two hundred and fifty euros"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_local_amount("R$ 10,50", "pt_BR").unwrap().to_words(),
+    ///     Ok(String::from("ten reais and fifty centavos"))
+    /// );
+    /// ```
+    pub fn parse_local_amount(amount: &str, locale: &str) -> Option<Self> {
+        let (num, currency) = amount::parse_amount(amount, locale)?;
+        Some(Self::new(num).currency(currency))
+    }
+
     /// Sets the language of the output
     ///
     /// For all of the available languages, see [`Lang`].
@@ -234,9 +559,320 @@ impl Num2Words {
         self
     }
 
+    /// Sets the type of output to fraction (`three quarters`)
+    ///
+    /// The number of the builder is the numerator and the given value is the
+    /// denominator. Improper fractions are read as mixed numbers. If either
+    /// part is not an integer, the pair is treated as a rational number and
+    /// reduced.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(3).fraction(4).to_words(),
+    ///     Ok(String::from("three quarters"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(21).fraction(8).to_words(),
+    ///     Ok(String::from("two and five eighths"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2.5).fraction(1).to_words(),
+    ///     Ok(String::from("two and a half"))
+    /// );
+    /// ```
+    pub fn fraction<T>(mut self, denominator: T) -> Self
+    where
+        T: Into<BigFloat>,
+    {
+        self.output = Output::Fraction(denominator.into());
+        self
+    }
+
+    /// Sets the type of output to percentage (`forty-two percent`)
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(12.5).percent().to_words(),
+    ///     Ok(String::from("twelve point five percent"))
+    /// );
+    /// ```
+    pub fn percent(mut self) -> Self {
+        self.output = Output::Percent;
+        self
+    }
+
+    /// Sets the type of output to scientific notation (`six point zero two
+    /// two times ten to the twenty-third`)
+    ///
+    /// Cardinal numbers too large for the named scales of the language are
+    /// read this way too.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::parse("6.022e23").unwrap().scientific().to_words(),
+    ///     Ok(String::from("six point zero two two times ten to the twenty-third"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse("1.6E-19")
+    ///         .unwrap()
+    ///         .lang(Lang::French)
+    ///         .prefer("virgule")
+    ///         .scientific()
+    ///         .to_words(),
+    ///     Ok(String::from("un virgule six fois dix puissance moins dix-neuf"))
+    /// );
+    /// ```
+    pub fn scientific(mut self) -> Self {
+        self.output = Output::Scientific;
+        self
+    }
+
+    /// Sets the type of output to engineering notation, whose exponent is a
+    /// multiple of three (`six hundred two point two times ten to the
+    /// twenty-first`)
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::parse("1.6e-19").unwrap().engineering().to_words(),
+    ///     Ok(String::from(
+    ///         "one hundred sixty times ten to the minus twenty-first"
+    ///     ))
+    /// );
+    /// ```
+    pub fn engineering(mut self) -> Self {
+        self.output = Output::Engineering;
+        self
+    }
+
+    /// Sets the type of output to compact form (`two point five million`)
+    ///
+    /// The number is rounded to the given significant digits and read with
+    /// its largest scale word. It is preceded by `about` when rounding
+    /// changed its value. The preference `half` reads a leading part ending
+    /// in a half as `two and a half million`.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(2_500_000).compact(3).to_words(),
+    ///     Ok(String::from("two point five million"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2_500_000).prefer("half").compact(3).to_words(),
+    ///     Ok(String::from("two and a half million"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2_987_654_321u64).compact(1).to_words(),
+    ///     Ok(String::from("about three billion"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2_500_000)
+    ///         .lang(Lang::French)
+    ///         .prefer("half")
+    ///         .compact(3)
+    ///         .to_words(),
+    ///     Ok(String::from("deux millions et demi"))
+    /// );
+    /// ```
+    pub fn compact(mut self, significant: usize) -> Self {
+        self.output = Output::Compact(significant);
+        self
+    }
+
+    /// Sets the output to an amount of money in compact form (`two point five
+    /// million dollars`)
+    ///
+    /// The amount is rounded as in [`Num2Words::compact`], and the currency
+    /// follows the scale word. Amounts below a thousand are read in full as
+    /// with [`Num2Words::currency`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(2_500_000).compact_currency(Currency::DOLLAR, 3).to_words(),
+    ///     Ok(String::from("two point five million dollars"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2_500_000)
+    ///         .lang(Lang::French)
+    ///         .prefer("virgule")
+    ///         .compact_currency(Currency::EUR, 3)
+    ///         .to_words(),
+    ///     Ok(String::from("deux virgule cinq millions d'euros"))
+    /// );
+    /// ```
+    pub fn compact_currency(mut self, currency: Currency, significant: usize) -> Self {
+        self.output = Output::CompactCurrency(significant);
+        self.currency = currency;
+        self
+    }
+
+    /// Sets the type of output to per mille (`forty-two per mille`)
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(42).permille().to_words(),
+    ///     Ok(String::from("forty-two per mille"))
+    /// );
+    /// ```
+    pub fn permille(mut self) -> Self {
+        self.output = Output::PerMille;
+        self
+    }
+
+    /// Sets the type of output to clock time, the number being the hour
+    /// (`two oh five pm`)
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(14).time(5, None).to_words(),
+    ///     Ok(String::from("two oh five pm"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(14).time(5, None).prefer("24h").to_words(),
+    ///     Ok(String::from("fourteen oh five"))
+    /// );
+    /// ```
+    pub fn time(mut self, minutes: u8, seconds: Option<u8>) -> Self {
+        self.output = Output::Time(minutes, seconds);
+        self
+    }
+
+    /// Sets the type of output to date, the number being the year
+    /// (`March third, nineteen oh-one`)
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(1901).date(3, 3).to_words(),
+    ///     Ok(String::from("March third, nineteen oh-one"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(1901).date(3, 3).prefer("dmy").to_words(),
+    ///     Ok(String::from("the third of March, nineteen oh-one"))
+    /// );
+    /// ```
+    pub fn date(mut self, month: u8, day: u8) -> Self {
+        self.output = Output::Date(month, day);
+        self
+    }
+
+    /// Sets the type of output to Roman numeral (`XLII`)
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(42).roman().to_words(),
+    ///     Ok(String::from("XLII"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(42).roman().prefer("lowercase").to_words(),
+    ///     Ok(String::from("xlii"))
+    /// );
+    /// ```
+    pub fn roman(mut self) -> Self {
+        self.output = Output::Roman;
+        self
+    }
+
+    /// Sets the type of output to regnal number following the given name
+    /// (`Elizabeth the second`)
+    ///
+    /// The number is written in lowercase in every language, or capitalized
+    /// with the "capitalized" preference.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(2).regnal("Elizabeth").to_words(),
+    ///     Ok(String::from("Elizabeth the second"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2).regnal("Elizabeth").prefer("capitalized").to_words(),
+    ///     Ok(String::from("Elizabeth the Second"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2).regnal("Isabel").lang(Lang::Spanish).prefer("f").to_words(),
+    ///     Ok(String::from("Isabel segunda"))
+    /// );
+    /// ```
+    pub fn regnal<T>(mut self, name: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.output = Output::Regnal(name.into());
+        self
+    }
+
+    /// Sets the type of output to measurement in the given unit
+    /// (`forty-two kilometres`)
+    ///
+    /// For all of the available units, see [`Unit`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Lang, Unit};
+    /// assert_eq!(
+    ///     Num2Words::new(1).unit(Unit::Kilogram).to_words(),
+    ///     Ok(String::from("one kilogram"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(3).unit(Unit::Celsius).lang(Lang::Spanish).to_words(),
+    ///     Ok(String::from("tres grados centígrados"))
+    /// );
+    /// ```
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.output = Output::Unit(unit);
+        self
+    }
+
+    /// Sets the type of output to number followed by the given noun in
+    /// agreement (`twenty-one apples`)
+    ///
+    /// See [`Noun`] for the description of a noun.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Gender, Lang, Noun, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(1).counted(Noun::new("apple", "apples")).to_words(),
+    ///     Ok(String::from("one apple"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(21)
+    ///         .lang(Lang::Spanish)
+    ///         .counted(Noun::new("libro", "libros"))
+    ///         .to_words(),
+    ///     Ok(String::from("veintiún libros"))
+    /// );
+    /// ```
+    pub fn counted(mut self, noun: Noun) -> Self {
+        self.output = Output::Counted(Box::new(noun));
+        self
+    }
+
     /// Sets the output to the currency it has been given
     ///
-    /// For all of the available currencies, see [`Currency`].
+    /// For all of the available currencies, see [`Currency`]. The amount is
+    /// rounded half away from zero to the minor unit of the currency, e.g.
+    /// 1.999 dollars are read as two dollars.
     ///
     /// Example:
     /// ```
@@ -245,6 +881,25 @@ impl Num2Words {
     ///     Num2Words::new(42.01).currency(Currency::DOLLAR).to_words(),
     ///     Ok(String::from("forty-two dollars and one cent"))
     /// );
+    /// assert_eq!(
+    ///     Num2Words::new(1.234).currency(Currency::KWD).to_words(),
+    ///     Ok(String::from("one kuwaiti dinar and two hundred thirty-four fils"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(100.5).currency(Currency::JPY).to_words(),
+    ///     Ok(String::from("one hundred one yen"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(0.00012345).currency(Currency::BTC).to_words(),
+    ///     Ok(String::from("twelve thousand three hundred and forty-five satoshis"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(0.00012345)
+    ///         .currency(Currency::BTC)
+    ///         .prefer("decimal")
+    ///         .to_words(),
+    ///     Ok(String::from("zero point zero zero zero one two three four five bitcoin"))
+    /// );
     /// ```
     pub fn currency(mut self, currency: Currency) -> Self {
         self.output = Output::Currency;
@@ -252,20 +907,104 @@ impl Num2Words {
         self
     }
 
+    /// Sets the output to the amount in the given currency as written on
+    /// cheques and invoices
+    ///
+    /// The amount is rounded to the minor unit of the currency, e.g. the cent,
+    /// whose writing follows the custom of the language unless set by the
+    /// preferences words, numeric or fraction.
+    /// The preference only closes the amount, and capitalized or uppercase
+    /// set its case.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(42.01)
+    ///         .cheque(Currency::DOLLAR)
+    ///         .prefer("capitalized")
+    ///         .to_words(),
+    ///     Ok(String::from("Forty-two and 01/100 dollars"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(42.01)
+    ///         .cheque(Currency::DOLLAR)
+    ///         .prefer("numeric")
+    ///         .prefer("only")
+    ///         .prefer("uppercase")
+    ///         .to_words(),
+    ///     Ok(String::from("FORTY-TWO DOLLARS AND 01 CENTS ONLY"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(42.01).lang(Lang::French).cheque(Currency::EUR).to_words(),
+    ///     Ok(String::from("quarante-deux euros et un centime"))
+    /// );
+    /// ```
+    pub fn cheque(mut self, currency: Currency) -> Self {
+        self.output = Output::Cheque;
+        self.currency = currency;
+        self
+    }
+
     /// Adds a preference parameter
     ///
+    /// # All languages accept:
+    /// * 12h/24h for the clock used by times
+    ///
+    /// * pairs/triples to read digit sequences by groups of two or three
+    ///
+    /// * lowercase and vinculum/apostrophus for Roman numerals, the latter
+    ///   for numbers of 4000 and above
+    ///
+    /// * short/long for the scale of large numbers, e.g. whether a billion
+    ///   is 10^9 or 10^12, English and Ukrainian defaulting to the short one
+    ///   and French and Spanish to the long one
+    ///
+    /// * half to read compact numbers ending in a half as "two and a half
+    ///   million"
+    ///
+    /// * decimal/minor to read an amount of money as a decimal number of the
+    ///   currency, e.g. "one point five dollars", or in its minor unit, e.g.
+    ///   "one hundred fifty cents", and gwei for amounts of ether; currencies
+    ///   without a minor unit in use, like the yen, are read in their unit
+    ///
+    /// * both to read both parts of an amount of money even when one is zero,
+    ///   e.g. "zero dollars and fifty cents", and conjunction:<word> to join
+    ///   them with another word of the language (and/with, et/avec, con/y,
+    ///   і/та) or with none, e.g. "conjunction:none"
+    ///
+    /// * words/numeric/fraction for the cents of cheques, e.g. "and one
+    ///   cent", "and 01 cents" or "and 01/100", only to close them, and
+    ///   capitalized/uppercase for their case
+    ///
+    /// * capitalized for regnal numbers, e.g. "Henry the Eighth"
+    ///
     /// # English language accepts:
     /// * oh and/or nil as replacements for "zero"
     ///
+    /// * mdy/dmy for the order of the month and the day in dates, the day
+    ///   coming first by default in the United Kingdom, India and Australia
+    ///
+    /// * double to collapse repeated digits, e.g. "double five"
+    ///
+    /// * p for the pence of British amounts, e.g. "fifty p" (`Lang::English_GB`)
+    ///
     /// # French language accepts:
     /// * feminine/f/féminin/feminin
     ///
     /// * reformed/1990/rectifié/rectification
     ///
+    /// * virgule/point
+    ///
+    /// * septante/soixante-dix, huitante/octante/quatre-vingts and
+    ///   nonante/quatre-vingt-dix, whatever the region, e.g. "octante"
+    ///
+    /// * rappen for the cents of the Swiss franc
+    ///
     /// # Spanish language accepts:
     /// * negativo/menos/bajo cero/prepended/appended
     /// * veinte
-    /// * coma/punto
+    /// * coma/punto, by default coma for the regional variants except Mexico
     /// * f/femenino/feminine
     /// * plural
     ///
@@ -307,10 +1046,93 @@ impl Num2Words {
 
     /// Builds the output
     pub fn to_words(self) -> Result<String, Num2Err> {
-        let lang = lang::to_language(self.lang, self.preferences);
+        let lang = lang::to_language(self.lang, self.preferences.clone());
         match self.output {
-            Output::Cardinal => lang.to_cardinal(self.num),
-            Output::Currency => lang.to_currency(self.num, self.currency),
+            Output::Cardinal => match lang.to_cardinal(self.num) {
+                // beyond the named scales of the language
+                Err(Num2Err::CannotConvert) if !self.num.is_nan() && !self.num.is_inf() => {
+                    let (mantissa, exponent) = to_scientific(self.num, 1);
+                    lang.to_scientific(mantissa, exponent)
+                }
+                words => words,
+            },
+            Output::Scientific | Output::Engineering => {
+                if self.num.is_nan() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                // zero has no exponent, so that it reads "zero"
+                if self.num.is_inf() || self.num.is_zero() {
+                    return lang.to_cardinal(self.num);
+                }
+                let step = match self.output {
+                    Output::Engineering => 3,
+                    _ => 1,
+                };
+                let (mantissa, exponent) = to_scientific(self.num, step);
+                lang.to_scientific(mantissa, exponent)
+            }
+            Output::Compact(significant) => {
+                if self.num.is_nan() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                if self.num.is_inf() || self.num.is_zero() {
+                    return lang.to_cardinal(self.num);
+                }
+                let (leading, scale, approximate) = to_compact(self.num, significant);
+                lang.to_compact(leading, scale, approximate)
+            }
+            Output::CompactCurrency(significant) => {
+                if self.num.is_nan() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                if !self.num.is_inf() && !self.num.is_zero() {
+                    let (leading, scale, approximate) = to_compact(self.num, significant);
+                    if scale > 0 {
+                        return lang.to_compact_currency(
+                            leading,
+                            scale,
+                            approximate,
+                            self.currency,
+                        );
+                    }
+                }
+                // amounts below a thousand are read in full, e.g. "one dollar
+                // and twenty-three cents"
+                let num = if self.num.is_inf() {
+                    self.num
+                } else {
+                    self.num
+                        .round(self.currency.minor_units(), RoundingMode::FromZero)
+                };
+                to_currency(lang.as_ref(), num, self.currency, &self.preferences)
+            }
+            Output::Currency => {
+                if self.num.is_nan() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                let num = if self.num.is_inf() {
+                    self.num
+                } else {
+                    // to the minor unit, e.g. a yen has no sen
+                    let minor_units = self.currency.minor_units();
+                    self.num.round(minor_units, RoundingMode::FromZero)
+                };
+                to_currency(lang.as_ref(), num, self.currency, &self.preferences)
+            }
+            Output::Cheque => {
+                if self.num.is_nan() || self.num.is_inf() || self.num.is_negative() {
+                    return Err(Num2Err::InvalidCheque);
+                }
+                to_cheque(
+                    lang.as_ref(),
+                    self.num
+                        .round(self.currency.minor_units(), RoundingMode::FromZero),
+                    self.currency,
+                    &self.preferences,
+                )
+            }
+            Output::Percent => lang.to_percent(self.num),
+            Output::PerMille => lang.to_permille(self.num),
             Output::Ordinal => {
                 if self.num.is_inf() {
                     return Err(Num2Err::InfiniteOrdinal);
@@ -344,17 +1166,580 @@ impl Num2Words {
                 }
                 lang.to_year(self.num)
             }
+            Output::Fraction(denominator) => {
+                if self.num.is_inf() || denominator.is_inf() {
+                    return Err(Num2Err::InfiniteFraction);
+                }
+                if denominator.is_zero() {
+                    return Err(Num2Err::ZeroDenominator);
+                }
+                let (numerator, denominator) = to_rational(self.num, denominator)?;
+                lang.to_fraction(numerator, denominator)
+            }
+            Output::Time(minutes, seconds) => {
+                let hours = match self.num.to_u64() {
+                    Some(h) if h < 24 && self.num.frac().is_zero() && !self.num.is_negative() => {
+                        h as u8
+                    }
+                    _ => return Err(Num2Err::InvalidTime),
+                };
+                if minutes > 59 || seconds.is_some_and(|s| s > 59) {
+                    return Err(Num2Err::InvalidTime);
+                }
+                lang.to_time(hours, minutes, seconds)
+            }
+            Output::Digits(groups) => lang.to_digits(&groups),
+            Output::Roman => to_roman(self.num, &self.preferences),
+            Output::Unit(unit) => lang.to_unit(self.num, unit),
+            Output::Counted(noun) => lang.to_counted(self.num, &noun),
+            Output::Regnal(name) => {
+                if self.num.is_inf() {
+                    return Err(Num2Err::InfiniteOrdinal);
+                }
+                if !self.num.frac().is_zero() {
+                    return Err(Num2Err::FloatingOrdinal);
+                }
+                if self.num.is_negative() {
+                    return Err(Num2Err::NegativeOrdinal);
+                }
+                if self.num.is_zero() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                let regnal = lang.to_regnal(self.num)?;
+                if !self.preferences.iter().any(|v| v == "capitalized") {
+                    return Ok(format!("{name} {regnal}"));
+                }
+                // the article stays lowercase, e.g. "Henry the Eighth"
+                let words: Vec<String> = regnal
+                    .split(' ')
+                    .map(|word| match word {
+                        "the" => String::from(word),
+                        _ => capitalize(word),
+                    })
+                    .collect();
+                Ok(format!("{name} {}", words.join(" ")))
+            }
+            Output::Date(month, day) => {
+                if self.num.is_inf() {
+                    return Err(Num2Err::InfiniteYear);
+                }
+                if !self.num.frac().is_zero() {
+                    return Err(Num2Err::FloatingYear);
+                }
+                // there is no year zero, 1 BC being followed by AD 1
+                if self.num.is_zero()
+                    || !(1..=12).contains(&month)
+                    || day == 0
+                    || day > days_in_month(self.num, month)
+                {
+                    return Err(Num2Err::InvalidDate);
+                }
+                lang.to_date(self.num, month, day)
+            }
         }
     }
 }
 
+/// Splits a finite number into a mantissa and a power of ten whose exponent
+/// is a multiple of `step`, e.g. `6.022e23` into `6.022` and `23`
+fn to_scientific(num: BigFloat, step: i64) -> (BigFloat, BigFloat) {
+    if num.is_zero() {
+        return (num, BigFloat::from(0));
+    }
+
+    // BigFloat displays its normalized decimal form, e.g. `6.022000…e+23`
+    let repr = num.to_string();
+    let (mantissa, exponent) = repr.split_once('e').unwrap_or((&repr, "0"));
+    let mut mantissa = BigFloat::parse(mantissa).unwrap_or(num);
+    let exponent: i64 = exponent.parse().unwrap_or_default();
+    for _ in 0..exponent.rem_euclid(step) {
+        mantissa *= BigFloat::from(10);
+    }
+    (
+        mantissa,
+        BigFloat::from(exponent - exponent.rem_euclid(step)),
+    )
+}
+
+/// Rounds a finite number to the given significant digits and splits it into
+/// a leading part and a power of a thousand, e.g. `2_512_000` to two digits
+/// into `2.5` and `2`, the rounding being approximate
+fn to_compact(num: BigFloat, significant: usize) -> (BigFloat, usize, bool) {
+    let (mantissa, exponent) = to_scientific(num, 1);
+    let rounded = mantissa.round(significant.max(1) - 1, RoundingMode::FromZero);
+    let approximate = rounded != mantissa;
+    let exponent = exponent.to_i64().unwrap_or_default();
+    let power = BigFloat::parse(&format!("1e{exponent}")).unwrap_or(BigFloat::from(1));
+    let rounded = rounded * power;
+
+    let (leading, exponent) = to_scientific(rounded, 3);
+    match exponent.to_i64() {
+        Some(exponent) if exponent > 0 => (leading, exponent as usize / 3, approximate),
+        _ => (rounded, 0, approximate),
+    }
+}
+
+/// Writes an amount of money in the unit set by the preferences: as a decimal
+/// number of the currency, in its minor unit, or in gwei for ether
+fn to_currency(
+    lang: &dyn Language,
+    num: BigFloat,
+    currency: Currency,
+    preferences: &[String],
+) -> Result<String, Num2Err> {
+    let reading = preferences
+        .iter()
+        .rev()
+        .find(|v| ["decimal", "minor", "gwei"].contains(&v.as_str()));
+    match reading.map(String::as_str) {
+        Some("decimal") => lang.to_currency_decimal(num, currency),
+        // currencies without a minor unit in use, like the yen, keep their unit
+        Some("minor") if currency.minor_units() > 0 => lang.to_counted(
+            num * minor_scale(currency),
+            &lang.currency_noun(currency, true)?,
+        ),
+        Some("gwei") if currency == Currency::ETH => {
+            lang.to_counted(num * BigFloat::from(1e9), &Noun::new("gwei", "gwei"))
+        }
+        _ => lang.to_currency(num, currency),
+    }
+}
+
+/// Writes an amount as on a cheque, then sets its case
+fn to_cheque(
+    lang: &dyn Language,
+    num: BigFloat,
+    currency: Currency,
+    preferences: &[String],
+) -> Result<String, Num2Err> {
+    let cents = if currency.minor_units() == 0 {
+        Some(ChequeCents::Words)
+    } else {
+        preferences.iter().rev().find_map(|v| match v.as_str() {
+            "words" => Some(ChequeCents::Words),
+            "numeric" => Some(ChequeCents::Digits),
+            "fraction" => Some(ChequeCents::Fraction),
+            _ => None,
+        })
+    };
+    let only = preferences.iter().any(|v| v == "only");
+    let words = lang.to_cheque(num, currency, cents, only)?;
+
+    if preferences.iter().any(|v| v == "uppercase") {
+        Ok(words.to_uppercase())
+    } else if preferences.iter().any(|v| v == "capitalized") {
+        Ok(capitalize(&words))
+    } else {
+        Ok(words)
+    }
+}
+
+/// Uppercases the first letter of the words
+fn capitalize(words: &str) -> String {
+    let mut chars = words.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Writes a number as a Roman numeral, which does not depend on the language
+fn to_roman(num: BigFloat, preferences: &[String]) -> Result<String, Num2Err> {
+    let style = match preferences
+        .iter()
+        .rev()
+        .find(|v| ["vinculum", "apostrophus"].contains(&v.as_str()))
+    {
+        Some(v) if v == "vinculum" => RomanStyle::Vinculum,
+        Some(_) => RomanStyle::Apostrophus,
+        None => RomanStyle::Standard,
+    };
+    if !num.frac().is_zero() || num.is_negative() {
+        return Err(Num2Err::InvalidRoman);
+    }
+    let num = num.to_u64().ok_or(Num2Err::InvalidRoman)?;
+    let roman = roman::to_roman(num, style).ok_or(Num2Err::InvalidRoman)?;
+
+    Ok(if preferences.iter().any(|v| v == "lowercase") {
+        roman.to_lowercase()
+    } else {
+        roman
+    })
+}
+
+/// Number of days in the given month of the (proleptic) Gregorian calendar
+fn days_in_month(year: BigFloat, month: u8) -> u8 {
+    let is_multiple = |n: i64| (year % BigFloat::from(n)).is_zero();
+    match month {
+        2 if is_multiple(4) && (!is_multiple(100) || is_multiple(400)) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Turns a numerator and a denominator into a pair of integers with a
+/// positive denominator
+///
+/// Pairs that are already integers are kept as is (`2/4` stays two quarters),
+/// whereas decimal parts are scaled up and reduced (`0.5/1` becomes `1/2`).
+/// `NaN` on either side cannot be turned into a fraction.
+fn to_rational(
+    mut numerator: BigFloat,
+    mut denominator: BigFloat,
+) -> Result<(BigFloat, BigFloat), Num2Err> {
+    if numerator.is_nan() || denominator.is_nan() {
+        return Err(Num2Err::CannotConvert);
+    }
+
+    if denominator.is_negative() {
+        numerator = -numerator;
+        denominator = -denominator;
+    }
+
+    if numerator.frac().is_zero() && denominator.frac().is_zero() {
+        return Ok((numerator, denominator));
+    }
+
+    let bf_10 = BigFloat::from(10);
+    while !numerator.frac().is_zero() || !denominator.frac().is_zero() {
+        numerator *= bf_10;
+        denominator *= bf_10;
+    }
+
+    let (mut a, mut b) = (numerator.abs(), denominator);
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+
+    Ok((numerator / a, denominator / a))
+}
+
 #[cfg(test)]
 mod tests {
+    use super::{to_compact, to_scientific};
     use crate::*;
+    use num_bigfloat::BigFloat;
 
     #[test]
     fn test_string_not_valid() {
         assert!(Num2Words::parse("not a number").is_none());
         assert!(Num2Words::parse("NAN").is_none());
     }
+
+    #[test]
+    fn test_time_not_valid() {
+        assert!(Num2Words::parse_time("14").is_none());
+        assert!(Num2Words::parse_time("13:00 pm").is_none());
+        assert!(Num2Words::parse_time("1:2:3:4").is_none());
+        assert_eq!(
+            Num2Words::parse_time("12:60").unwrap().to_words(),
+            Err(Num2Err::InvalidTime)
+        );
+        assert_eq!(
+            Num2Words::new(-1).time(0, None).to_words(),
+            Err(Num2Err::InvalidTime)
+        );
+        assert_eq!(
+            Num2Words::new(1.5).time(0, None).to_words(),
+            Err(Num2Err::InvalidTime)
+        );
+        assert_eq!(
+            Num2Words::new(1).time(0, Some(60)).to_words(),
+            Err(Num2Err::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn test_date_not_valid() {
+        assert_eq!(
+            Num2Words::new(2024).date(13, 1).to_words(),
+            Err(Num2Err::InvalidDate)
+        );
+        assert_eq!(
+            Num2Words::new(2024).date(4, 31).to_words(),
+            Err(Num2Err::InvalidDate)
+        );
+        assert_eq!(
+            Num2Words::new(2023).date(2, 29).to_words(),
+            Err(Num2Err::InvalidDate)
+        );
+        assert_eq!(
+            Num2Words::new(2024).date(1, 0).to_words(),
+            Err(Num2Err::InvalidDate)
+        );
+        assert_eq!(
+            Num2Words::new(0).date(3, 15).to_words(),
+            Err(Num2Err::InvalidDate)
+        );
+        assert_eq!(
+            Num2Words::new(2024.5).date(1, 1).to_words(),
+            Err(Num2Err::FloatingYear)
+        );
+        assert!(Num2Words::new(2000).date(2, 29).to_words().is_ok());
+    }
+
+    #[test]
+    fn test_digits_not_valid() {
+        assert!(Num2Words::parse_digits("").is_none());
+        assert!(Num2Words::parse_digits("--").is_none());
+        assert!(Num2Words::parse_digits("555-O142").is_none());
+        assert!(Num2Words::parse_digits("+1 555").is_none());
+    }
+
+    #[test]
+    fn test_roman() {
+        assert_eq!(
+            Num2Words::new(1994).roman().to_words(),
+            Ok(String::from("MCMXCIV"))
+        );
+        assert_eq!(
+            Num2Words::new(5000).roman().prefer("vinculum").to_words(),
+            Ok(String::from("V\u{305}"))
+        );
+        assert_eq!(
+            Num2Words::new(5000)
+                .roman()
+                .prefer("apostrophus")
+                .to_words(),
+            Ok(String::from("IↃↃ"))
+        );
+        assert_eq!(
+            Num2Words::new(0).roman().to_words(),
+            Err(Num2Err::InvalidRoman)
+        );
+        assert_eq!(
+            Num2Words::new(-4).roman().to_words(),
+            Err(Num2Err::InvalidRoman)
+        );
+        assert_eq!(
+            Num2Words::new(4.2).roman().to_words(),
+            Err(Num2Err::InvalidRoman)
+        );
+        assert_eq!(
+            Num2Words::parse_roman("xlii")
+                .unwrap()
+                .lang(Lang::French)
+                .to_words(),
+            Ok(String::from("quarante-deux"))
+        );
+        assert!(Num2Words::parse_roman("IIII").is_none());
+        assert!(Num2Words::parse_roman("MMMM").is_none());
+        assert_eq!(
+            Num2Words::parse("C").unwrap().to_words(),
+            Ok(String::from("one hundred"))
+        );
+        assert!(Num2Words::parse("MMMM").is_none());
+    }
+
+    #[test]
+    fn test_to_scientific() {
+        let parts = |num: &str, step: i64| {
+            let (mantissa, exponent) = to_scientific(BigFloat::parse(num).unwrap(), step);
+            (mantissa.to_f64(), exponent.to_i64().unwrap())
+        };
+        assert_eq!(parts("6.022e23", 1), (6.022, 23));
+        assert_eq!(parts("1.6e-19", 1), (1.6, -19));
+        assert_eq!(parts("-42", 1), (-4.2, 1));
+        assert_eq!(parts("0", 1), (0.0, 0));
+        assert_eq!(parts("6.022e23", 3), (602.2, 21));
+        assert_eq!(parts("1.6e-19", 3), (160.0, -21));
+        assert_eq!(parts("12345", 3), (12.345, 3));
+    }
+
+    #[test]
+    fn test_to_compact() {
+        let parts = |num: f64, significant: usize| {
+            let (leading, scale, approximate) = to_compact(BigFloat::from(num), significant);
+            (leading.to_f64(), scale, approximate)
+        };
+        assert_eq!(parts(2_500_000.0, 3), (2.5, 2, false));
+        assert_eq!(parts(2_512_000.0, 2), (2.5, 2, true));
+        assert_eq!(parts(999_999.0, 2), (1.0, 2, true));
+        assert_eq!(parts(42_195.0, 0), (40.0, 1, true));
+        assert_eq!(parts(-123.4, 3), (-123.0, 0, true));
+        assert_eq!(parts(0.012, 1), (0.01, 0, true));
+    }
+
+    #[test]
+    fn test_regnal() {
+        assert_eq!(
+            Num2Words::parse_regnal("Jean-Paul II")
+                .unwrap()
+                .lang(Lang::French)
+                .to_words(),
+            Ok(String::from("Jean-Paul deux"))
+        );
+        assert_eq!(
+            Num2Words::parse_regnal("Pope Pius 12").unwrap().to_words(),
+            Ok(String::from("Pope Pius the twelfth"))
+        );
+        assert_eq!(
+            Num2Words::parse_regnal("Felipe VI")
+                .unwrap()
+                .lang(Lang::Spanish)
+                .prefer("capitalized")
+                .to_words(),
+            Ok(String::from("Felipe Sexto"))
+        );
+        assert!(Num2Words::parse_regnal("XIV").is_none());
+        assert!(Num2Words::parse_regnal("Louis IIII").is_none());
+        assert_eq!(
+            Num2Words::new(0).regnal("Louis").to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(-1).regnal("Louis").to_words(),
+            Err(Num2Err::NegativeOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_parse_measurement() {
+        assert_eq!(
+            Num2Words::parse_measurement("-3 °C").unwrap().to_words(),
+            Ok(String::from("minus three degrees Celsius"))
+        );
+        assert_eq!(
+            Num2Words::parse_measurement("2mph").unwrap().to_words(),
+            Ok(String::from("two miles per hour"))
+        );
+        assert!(Num2Words::parse_measurement("42").is_none());
+        assert!(Num2Words::parse_measurement("km").is_none());
+        assert!(Num2Words::parse_measurement("42 parsecs").is_none());
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(
+            Num2Words::parse_time("12:00 am")
+                .unwrap()
+                .prefer("24h")
+                .to_words(),
+            Ok(String::from("zero hundred hours"))
+        );
+        assert_eq!(
+            Num2Words::parse_time("12:15 PM")
+                .unwrap()
+                .prefer("24h")
+                .to_words(),
+            Ok(String::from("twelve fifteen"))
+        );
+        assert_eq!(
+            Num2Words::parse_time("23:59:59")
+                .unwrap()
+                .prefer("24h")
+                .to_words(),
+            Ok(String::from(
+                "twenty-three fifty-nine and fifty-nine seconds"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_every_currency() {
+        let langs = [
+            Lang::English,
+            Lang::English_US,
+            Lang::English_GB,
+            Lang::English_IN,
+            Lang::English_AU,
+            Lang::English_CA,
+            Lang::French,
+            Lang::French_BE,
+            Lang::French_CH,
+            Lang::French_CA,
+            Lang::Spanish,
+            Lang::Spanish_ES,
+            Lang::Spanish_MX,
+            Lang::Spanish_AR,
+            Lang::Spanish_CO,
+            Lang::Spanish_CL,
+            Lang::Ukrainian,
+        ];
+        let amounts: [f64; 6] = [0.0, 1.0, 2.5, 21.01, -3.05, 1e6];
+        for lang in langs {
+            for currency in Currency::all() {
+                let currency = *currency;
+                for num in amounts {
+                    for pref in ["", "both", "decimal", "minor"] {
+                        assert!(
+                            Num2Words::new(num)
+                                .lang(lang)
+                                .currency(currency)
+                                .prefer(pref)
+                                .to_words()
+                                .is_ok(),
+                            "{:?} {:?} {} {}",
+                            lang,
+                            currency,
+                            num,
+                            pref
+                        );
+                    }
+                    for pref in ["numeric", "fraction"] {
+                        assert!(
+                            Num2Words::new(num.abs())
+                                .lang(lang)
+                                .cheque(currency)
+                                .prefer(pref)
+                                .to_words()
+                                .is_ok(),
+                            "{:?} {:?} cheque {} {}",
+                            lang,
+                            currency,
+                            num,
+                            pref
+                        );
+                    }
+                    assert!(
+                        Num2Words::new(num)
+                            .lang(lang)
+                            .compact_currency(currency, 2)
+                            .to_words()
+                            .is_ok(),
+                        "{:?} {:?} compact {}",
+                        lang,
+                        currency,
+                        num
+                    );
+                }
+                assert!(
+                    Num2Words::parse("inf")
+                        .unwrap()
+                        .lang(lang)
+                        .currency(currency)
+                        .to_words()
+                        .is_ok(),
+                    "{:?} {:?} inf",
+                    lang,
+                    currency
+                );
+                for pref in ["", "decimal", "minor"] {
+                    assert_eq!(
+                        Num2Words::new(f64::NAN)
+                            .lang(lang)
+                            .currency(currency)
+                            .prefer(pref)
+                            .to_words(),
+                        Err(Num2Err::CannotConvert),
+                        "{:?} {:?} NaN {}",
+                        lang,
+                        currency,
+                        pref
+                    );
+                }
+                assert_eq!(
+                    Num2Words::new(f64::NAN)
+                        .lang(lang)
+                        .compact_currency(currency, 2)
+                        .to_words(),
+                    Err(Num2Err::CannotConvert),
+                    "{:?} {:?} compact NaN",
+                    lang,
+                    currency
+                );
+            }
+        }
+    }
 }
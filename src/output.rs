@@ -1,3 +1,5 @@
+use crate::{Noun, Unit};
+use num_bigfloat::BigFloat;
 use std::str::FromStr;
 
 /// Type of the output `num2words` give
@@ -6,12 +8,52 @@ pub enum Output {
     Cardinal,
     /// Number in currency form, e.g., `forty-two dollars`
     Currency,
+    /// Number as an amount written on a cheque, e.g., `forty-two and 01/100
+    /// dollars`
+    Cheque,
     /// Number in ordinal form, e.g., `forty-second`
     Ordinal,
     /// Number in ordinal form written in number, e.g., `42nd`
     OrdinalNum,
     /// Number in year form, e.g., `nineteen oh-one`
     Year,
+    /// Number as the numerator of a fraction with the given denominator,
+    /// e.g., `three quarters`
+    Fraction(BigFloat),
+    /// Number in percentage form, e.g., `forty-two percent`
+    Percent,
+    /// Number in per-mille form, e.g., `forty-two per mille`
+    PerMille,
+    /// Number in scientific notation, e.g., `six point zero two two times ten
+    /// to the twenty-third`
+    Scientific,
+    /// Number in engineering notation, whose exponent is a multiple of three,
+    /// e.g., `six hundred two point two times ten to the twenty-first`
+    Engineering,
+    /// Number rounded to the given significant digits and read with its
+    /// largest scale word, e.g., `two point five million`
+    Compact(usize),
+    /// Amount of money in compact form, the currency following the scale
+    /// word, e.g., `two point five million dollars`
+    CompactCurrency(usize),
+    /// Number as the hour of a clock time with the given minutes and
+    /// seconds, e.g., `two oh five pm`
+    Time(u8, Option<u8>),
+    /// Number as the year of a date with the given month and day, e.g.,
+    /// `March third, nineteen oh-one`
+    Date(u8, u8),
+    /// Groups of digits read one by one, e.g., `five five five oh one four two`
+    Digits(Vec<String>),
+    /// Number as a Roman numeral, e.g., `XLII`
+    Roman,
+    /// Number as the regnal number following the given name, e.g.,
+    /// `Henry the eighth`
+    Regnal(String),
+    /// Number as a measurement in the given unit, e.g., `forty-two kilometres`
+    Unit(Unit),
+    /// Number followed by the given noun in agreement, e.g.,
+    /// `twenty-one apples`
+    Counted(Box<Noun>),
 }
 
 impl FromStr for Output {
@@ -24,16 +66,30 @@ impl FromStr for Output {
     /// | ------------- | -------------------- |
     /// | `cardinal`    | `Output::Cardinal`   |
     /// | `currency`    | `Output::Currency`   |
+    /// | `cheque`      | `Output::Cheque`     |
     /// | `ordinal`     | `Output::Ordinal`    |
     /// | `ordinal_num` | `Output::OrdinalNum` |
     /// | `year`        | `Output::Year`       |
+    /// | `percent`     | `Output::Percent`    |
+    /// | `permille`    | `Output::PerMille`   |
+    /// | `scientific`  | `Output::Scientific` |
+    /// | `engineering` | `Output::Engineering` |
+    /// | `compact`     | `Output::Compact(3)` |
+    /// | `roman`       | `Output::Roman`      |
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
             "cardinal" => Ok(Output::Cardinal),
             "currency" => Ok(Output::Currency),
+            "cheque" => Ok(Output::Cheque),
             "ordinal" => Ok(Output::Ordinal),
             "ordinal_num" => Ok(Output::OrdinalNum),
             "year" => Ok(Output::Year),
+            "percent" => Ok(Output::Percent),
+            "permille" => Ok(Output::PerMille),
+            "scientific" => Ok(Output::Scientific),
+            "engineering" => Ok(Output::Engineering),
+            "compact" => Ok(Output::Compact(3)),
+            "roman" => Ok(Output::Roman),
             _ => Err(()),
         }
     }
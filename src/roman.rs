@@ -0,0 +1,154 @@
+/// Notation used for Roman numerals of 4000 and above
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RomanStyle {
+    /// Only values up to MMMCMXCIX (3999)
+    Standard,
+    /// Thousands written with an overline, e.g. `V̅` (5000)
+    Vinculum,
+    /// Thousands written with reversed Cs, e.g. `IↃↃ` (5000)
+    Apostrophus,
+}
+
+const SYMBOLS: [(u64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+const APOSTROPHUS: [(u64, &str); 5] = [
+    (100000, "CCCIↃↃↃ"),
+    (50000, "IↃↃↃ"),
+    (10000, "CCIↃↃ"),
+    (5000, "IↃↃ"),
+    (1000, "CIↃ"),
+];
+
+const OVERLINE: char = '\u{0305}';
+
+fn standard(mut num: u64) -> String {
+    let mut roman = String::new();
+    for (value, symbol) in SYMBOLS {
+        while num >= value {
+            roman.push_str(symbol);
+            num -= value;
+        }
+    }
+    roman
+}
+
+/// Writes a positive number as a Roman numeral, if it can be represented in
+/// the given style
+pub fn to_roman(num: u64, style: RomanStyle) -> Option<String> {
+    if num == 0 {
+        return None;
+    }
+
+    match style {
+        RomanStyle::Standard | RomanStyle::Vinculum if num < 4000 => Some(standard(num)),
+        RomanStyle::Vinculum if num < 4_000_000 => {
+            // thousands below IV̅ keep their Ms, e.g. MMMCMXCIX
+            let (thousands, rest) = match num / 1000 % 10 {
+                0..=3 => (num / 10000 * 10, num % 10000),
+                _ => (num / 1000, num % 1000),
+            };
+            let mut roman = String::new();
+            for c in standard(thousands).chars() {
+                roman.push(c);
+                roman.push(OVERLINE);
+            }
+            roman.push_str(&standard(rest));
+            Some(roman)
+        }
+        RomanStyle::Apostrophus if num < 500_000 => {
+            let mut num = num;
+            let mut roman = String::new();
+            for (value, symbol) in APOSTROPHUS {
+                while num >= value {
+                    roman.push_str(symbol);
+                    num -= value;
+                }
+            }
+            roman.push_str(&standard(num));
+            Some(roman)
+        }
+        _ => None,
+    }
+}
+
+/// Reads a Roman numeral written in the standard notation, either in
+/// uppercase or in lowercase
+///
+/// Only the canonical subtractive spelling is accepted, so that no symbol is
+/// repeated more than three times, e.g. neither IIII nor MMMM are.
+pub fn parse_roman(roman: &str) -> Option<u64> {
+    let upper = roman.to_uppercase();
+    let mut rest = upper.as_str();
+    let mut num = 0;
+    for (value, symbol) in SYMBOLS {
+        while let Some(tail) = rest.strip_prefix(symbol) {
+            num += value;
+            rest = tail;
+        }
+    }
+
+    // only the canonical spelling is accepted, e.g. IIII or VX are not, which
+    // limits the numerals to MMMCMXCIX (3999) as for `RomanStyle::Standard`
+    if !rest.is_empty() || num == 0 || num >= 4000 || standard(num) != upper {
+        return None;
+    }
+    Some(num)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_roman() {
+        assert_eq!(to_roman(42, RomanStyle::Standard).unwrap(), "XLII");
+        assert_eq!(to_roman(1994, RomanStyle::Standard).unwrap(), "MCMXCIV");
+        assert_eq!(to_roman(3999, RomanStyle::Standard).unwrap(), "MMMCMXCIX");
+        assert_eq!(to_roman(4000, RomanStyle::Standard), None);
+        assert_eq!(to_roman(0, RomanStyle::Standard), None);
+
+        assert_eq!(
+            to_roman(4000, RomanStyle::Vinculum).unwrap(),
+            "I\u{305}V\u{305}"
+        );
+        assert_eq!(
+            to_roman(12345, RomanStyle::Vinculum).unwrap(),
+            "X\u{305}MMCCCXLV"
+        );
+        assert_eq!(to_roman(4_000_000, RomanStyle::Vinculum), None);
+
+        assert_eq!(to_roman(5000, RomanStyle::Apostrophus).unwrap(), "IↃↃ");
+        assert_eq!(
+            to_roman(16001, RomanStyle::Apostrophus).unwrap(),
+            "CCIↃↃIↃↃCIↃI"
+        );
+    }
+
+    #[test]
+    fn test_parse_roman() {
+        assert_eq!(parse_roman("XIV"), Some(14));
+        assert_eq!(parse_roman("mcmxciv"), Some(1994));
+        assert_eq!(parse_roman("MMMCMXCIX"), Some(3999));
+        assert_eq!(parse_roman("IIII"), None);
+        assert_eq!(parse_roman("MMMM"), None);
+        assert_eq!(parse_roman("XXXX"), None);
+        assert_eq!(parse_roman("VX"), None);
+        assert_eq!(parse_roman("IC"), None);
+        assert_eq!(parse_roman(""), None);
+        assert_eq!(parse_roman("Louis"), None);
+    }
+}
@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+/// Defines units of measurement
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Unit {
+    /// Kilometre (km)
+    Kilometre,
+    /// Metre (m)
+    Metre,
+    /// Centimetre (cm)
+    Centimetre,
+    /// Millimetre (mm)
+    Millimetre,
+    /// Mile (mi)
+    Mile,
+    /// Foot (ft)
+    Foot,
+    /// Inch (in)
+    Inch,
+    /// Tonne (t)
+    Tonne,
+    /// Kilogram (kg)
+    Kilogram,
+    /// Gram (g)
+    Gram,
+    /// Pound (lb)
+    Pound,
+    /// Litre (l)
+    Litre,
+    /// Millilitre (ml)
+    Millilitre,
+    /// Degree Celsius (°C)
+    Celsius,
+    /// Degree Fahrenheit (°F)
+    Fahrenheit,
+    /// Kilometre per hour (km/h)
+    KilometrePerHour,
+    /// Mile per hour (mph)
+    MilePerHour,
+}
+
+impl FromStr for Unit {
+    type Err = ();
+
+    /// Parses the symbol of a unit
+    ///
+    /// | Symbol        | Unit                     |
+    /// | ------------- | ------------------------ |
+    /// | `km`          | `Unit::Kilometre`        |
+    /// | `m`           | `Unit::Metre`            |
+    /// | `cm`          | `Unit::Centimetre`       |
+    /// | `mm`          | `Unit::Millimetre`       |
+    /// | `mi`          | `Unit::Mile`             |
+    /// | `ft`          | `Unit::Foot`             |
+    /// | `in`          | `Unit::Inch`             |
+    /// | `t`           | `Unit::Tonne`            |
+    /// | `kg`          | `Unit::Kilogram`         |
+    /// | `g`           | `Unit::Gram`             |
+    /// | `lb`          | `Unit::Pound`            |
+    /// | `l`, `L`      | `Unit::Litre`            |
+    /// | `ml`, `mL`    | `Unit::Millilitre`       |
+    /// | `°C`          | `Unit::Celsius`          |
+    /// | `°F`          | `Unit::Fahrenheit`       |
+    /// | `km/h`        | `Unit::KilometrePerHour` |
+    /// | `mph`         | `Unit::MilePerHour`      |
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        match symbol {
+            "km" => Ok(Unit::Kilometre),
+            "m" => Ok(Unit::Metre),
+            "cm" => Ok(Unit::Centimetre),
+            "mm" => Ok(Unit::Millimetre),
+            "mi" => Ok(Unit::Mile),
+            "ft" => Ok(Unit::Foot),
+            "in" => Ok(Unit::Inch),
+            "t" => Ok(Unit::Tonne),
+            "kg" => Ok(Unit::Kilogram),
+            "g" => Ok(Unit::Gram),
+            "lb" => Ok(Unit::Pound),
+            "l" | "L" => Ok(Unit::Litre),
+            "ml" | "mL" => Ok(Unit::Millilitre),
+            "°C" => Ok(Unit::Celsius),
+            "°F" => Ok(Unit::Fahrenheit),
+            "km/h" => Ok(Unit::KilometrePerHour),
+            "mph" => Ok(Unit::MilePerHour),
+            _ => Err(()),
+        }
+    }
+}